@@ -1,21 +1,27 @@
 use {
+    chrono::{DateTime, Utc},
     clap::{
         crate_description, crate_name, crate_version, value_t_or_exit, App, AppSettings, Arg,
         ArgMatches, SubCommand,
     },
     solana_clap_utils::{
-        input_parsers::{keypair_of, pubkey_of},
-        input_validators::{is_keypair, is_url, is_valid_pubkey, is_within_range},
+        input_parsers::{keypair_of, pubkey_of, value_of},
+        input_validators::{is_keypair, is_parsable, is_url, is_valid_pubkey, is_within_range},
         keypair::{signer_from_path, CliSignerInfo},
     },
     solana_client::rpc_client::RpcClient,
     solana_remote_wallet::remote_wallet::RemoteWalletManager,
     solana_scoring::{
-        id,
-        state::{Mint, MintState},
+        get_score_account_address, id,
+        instruction::ScoreReturnData,
+        state::{
+            AccountType, DecayPolicy, Mint, MintState, MintV0, PointRepresentation, ScoreAccount,
+            UnderflowPolicy,
+        },
         utils::try_from_slice_checked,
     },
     solana_sdk::{
+        clock::UnixTimestamp,
         commitment_config::CommitmentConfig,
         native_token::lamports_to_sol,
         pubkey::Pubkey,
@@ -37,7 +43,7 @@ pub fn is_short<T>(string: T) -> Result<(), String>
 where
     T: AsRef<str> + Display,
 {
-    if string.as_ref().len() >= 256 {
+    if string.as_ref().len() > Mint::MAX_METADATA_URI_LENGTH {
         return Err(format!("too long: {}", string));
     }
     Ok(())
@@ -63,6 +69,21 @@ where
     Ok(())
 }
 
+pub fn is_rfc3339_datetime<T>(string: T) -> Result<(), String>
+where
+    T: AsRef<str> + Display,
+{
+    DateTime::parse_from_rfc3339(string.as_ref())
+        .map(|_| ())
+        .map_err(|err| format!("{}", err))
+}
+
+fn timestamp_of(matches: &ArgMatches<'_>, name: &str) -> Option<UnixTimestamp> {
+    matches
+        .value_of(name)
+        .map(|value| DateTime::parse_from_rfc3339(value).unwrap().timestamp())
+}
+
 fn new_throwaway_signer() -> (Box<dyn Signer>, Pubkey) {
     let keypair = Keypair::new();
     let pubkey = keypair.pubkey();
@@ -141,6 +162,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         .index(1)
                         .required(true)
                         .help("The address of the mint to be shown"),
+                )
+                .arg(
+                    Arg::with_name("wallet_address")
+                        .long("wallet")
+                        .value_name("ADDRESS")
+                        .validator(is_valid_pubkey)
+                        .takes_value(true)
+                        .help("Also show this wallet's points, headroom and stats"),
                 ),
         )
         .subcommand(
@@ -176,6 +205,89 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         .takes_value(true)
                         .help("Specify the freeze authority address. Defaults to unset."),
                 )
+                .arg(
+                    Arg::with_name("clamp_slashing")
+                        .long("clamp-slashing")
+                        .takes_value(false)
+                        .help(
+                            "Clamp scores to zero when slashing more points than an \
+                             account holds. By default such slashes are rejected.",
+                        ),
+                )
+                .arg(
+                    Arg::with_name("allow_self_close")
+                        .long("allow-self-close")
+                        .takes_value(false)
+                        .help("Allow wallets to close their own score accounts to reclaim rent."),
+                )
+                .arg(
+                    Arg::with_name("signed")
+                        .long("signed")
+                        .takes_value(false)
+                        .conflicts_with("clamp_slashing")
+                        .help("Allow scores to become negative, such as for ratings or penalties."),
+                )
+                .arg(
+                    Arg::with_name("start_time")
+                        .long("start-time")
+                        .value_name("RFC3339_DATETIME")
+                        .validator(is_rfc3339_datetime)
+                        .takes_value(true)
+                        .help("Specify the time before which points may not be issued or slashed."),
+                )
+                .arg(
+                    Arg::with_name("end_time")
+                        .long("end-time")
+                        .value_name("RFC3339_DATETIME")
+                        .validator(is_rfc3339_datetime)
+                        .takes_value(true)
+                        .help("Specify the time from which the mint behaves as frozen."),
+                )
+                .arg(
+                    Arg::with_name("decay_half_life")
+                        .long("decay-half-life")
+                        .value_name("SECONDS")
+                        .validator(is_parsable::<u64>)
+                        .takes_value(true)
+                        .conflicts_with("decay_per_day")
+                        .help("Halve scores every SECONDS while a wallet is inactive."),
+                )
+                .arg(
+                    Arg::with_name("decay_per_day")
+                        .long("decay-per-day")
+                        .value_name("POINTS")
+                        .validator(is_parsable::<u64>)
+                        .takes_value(true)
+                        .help("Decrease scores by POINTS per day while a wallet is inactive."),
+                )
+                .arg(
+                    Arg::with_name("max_points_per_account")
+                        .long("max-points-per-account")
+                        .value_name("POINTS")
+                        .validator(is_parsable::<u64>)
+                        .takes_value(true)
+                        .help("Limit the points any single score account may hold."),
+                )
+                .arg(
+                    Arg::with_name("max_total_supply")
+                        .long("max-total-supply")
+                        .value_name("POINTS")
+                        .validator(is_parsable::<u64>)
+                        .takes_value(true)
+                        .help("Limit the total points the mint may ever issue."),
+                )
+                .arg(
+                    Arg::with_name("stat")
+                        .long("stat")
+                        .value_name("NAME")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .help(
+                            "Add a named stat slot to every score account, such as kills \
+                             or wins. May be repeated up to 8 times.",
+                        ),
+                )
                 .arg(
                     Arg::with_name("metadata_uri")
                         .long("uri")
@@ -184,7 +296,77 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         .takes_value(true)
                         .help(
                             "Specify the JSON URI containing metadata for the score. \
-                             URI may be no longer than 255 bytes.",
+                             URI may be no longer than 128 bytes.",
+                        ),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("freeze-mint")
+                .about("Freeze a scoring mint, preventing any further score changes")
+                .arg(
+                    Arg::with_name("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .validator(is_valid_pubkey)
+                        .index(1)
+                        .required(true)
+                        .help("The address of the mint to be frozen"),
+                )
+                .arg(
+                    Arg::with_name("freeze_authority")
+                        .long("freeze-authority")
+                        .value_name("KEYPAIR")
+                        .validator(is_keypair)
+                        .takes_value(true)
+                        .help(
+                            "Specify the freeze authority keypair. \
+                             Defaults to the client keypair.",
+                        ),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("migrate-mint")
+                .about("Upgrade a scoring mint to the current account layout")
+                .arg(
+                    Arg::with_name("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .validator(is_valid_pubkey)
+                        .index(1)
+                        .required(true)
+                        .help("The address of the mint to be migrated"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("update-metadata-uri")
+                .about("Replace the metadata URI of a scoring mint")
+                .arg(
+                    Arg::with_name("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .validator(is_valid_pubkey)
+                        .index(1)
+                        .required(true)
+                        .help("The address of the mint to be updated"),
+                )
+                .arg(
+                    Arg::with_name("metadata_uri")
+                        .long("uri")
+                        .value_name("ADDRESS")
+                        .validator(is_short_url)
+                        .takes_value(true)
+                        .required(true)
+                        .help(
+                            "Specify the JSON URI containing metadata for the score. \
+                             URI may be no longer than 128 bytes.",
+                        ),
+                )
+                .arg(
+                    Arg::with_name("scoring_authority")
+                        .long("scoring-authority")
+                        .value_name("KEYPAIR")
+                        .validator(is_keypair)
+                        .takes_value(true)
+                        .help(
+                            "Specify the scoring authority keypair. \
+                             Defaults to the client keypair.",
                         ),
                 ),
         )
@@ -219,28 +401,112 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     match (sub_command, sub_matches) {
         ("get-mint-details", Some(arg_matches)) => {
-            // let user_address =
-            //     pubkey_of(arg_matches, "user_address").unwrap_or(config.keypair.pubkey());
-            // let house_addr = nobilitydao::get_house_address(&user_address);
-            // println!("House Address: {}", house_addr);
-            // let housedata = get_house(&rpc_client, &house_addr)?;
-            // let coa_url = housedata.coat_of_arms;
-            // let display_name = housedata.display_name;
-            // println!("Display Name: {}", display_name);
-            // println!("Coat of Arms: {}", coa_url);
+            let mint = pubkey_of(arg_matches, "mint_address").unwrap();
+            let mintdata = get_mint(&rpc_client, &mint)?;
+            let display_authority = |authority: Option<Pubkey>| {
+                authority.map_or("none".to_string(), |authority| authority.to_string())
+            };
+            println!("Mint Address: {}", mint);
+            println!(
+                "State: {:?}",
+                mintdata.effective_state(Utc::now().timestamp())
+            );
+            println!(
+                "Score Authority: {}",
+                display_authority(mintdata.score_authority)
+            );
+            println!(
+                "Freeze Authority: {}",
+                display_authority(mintdata.freeze_authority)
+            );
+            println!("Point Representation: {:?}", mintdata.point_representation);
+            println!("Season: {}", mintdata.season);
+            println!("Metadata URI: {}", mintdata.metadata_uri);
+            println!("Total Issued: {}", mintdata.total_issued);
+            match (mintdata.max_total_supply, mintdata.remaining_supply()) {
+                (Some(max_total_supply), Some(remaining_supply)) => println!(
+                    "Max Total Supply: {} ({} remaining)",
+                    max_total_supply, remaining_supply
+                ),
+                _ => println!("Max Total Supply: unlimited"),
+            }
+            match mintdata.max_points_per_account {
+                Some(max_points_per_account) => {
+                    println!("Max Points Per Account: {}", max_points_per_account)
+                }
+                None => println!("Max Points Per Account: unlimited"),
+            }
+            if !mintdata.stat_names.is_empty() {
+                println!("Stats: {}", mintdata.stat_names.join(", "));
+            }
+
+            if let Some(wallet) = pubkey_of(arg_matches, "wallet_address") {
+                let score_account = get_score_account_address(&wallet, &mint, mintdata.season);
+                let scoredata = get_score_account(&rpc_client, &score_account)?;
+                let score =
+                    simulate_get_score(&rpc_client, &config.keypair, &mint, &score_account)?;
+                let points = score.points;
+                println!("Wallet Points: {}", points);
+                if score.account_frozen {
+                    println!("Wallet Score Account: frozen");
+                }
+                // Issuance is bounded by both caps. Stored points are never below
+                // the decayed points, so the headroom is never overstated.
+                let account_headroom = mintdata
+                    .max_points_per_account
+                    .map(|max| (max as i128 - scoredata.points.as_i128()).max(0));
+                let supply_headroom = mintdata.remaining_supply().map(i128::from);
+                if let Some(headroom) = account_headroom.into_iter().chain(supply_headroom).min() {
+                    println!("Wallet Headroom: {}", headroom);
+                }
+                for (name, value) in scoredata.stats(&mintdata) {
+                    println!("Wallet {}: {}", name, value);
+                }
+            }
             Ok(())
         }
         ("create-scoring-mint", Some(arg_matches)) => {
             let user_keypair = config.keypair;
             let (mint_signer, mint) = get_signer(arg_matches, "token_keypair", &mut wallet_manager)
                 .unwrap_or_else(new_throwaway_signer);
-            let scoring_authority = pubkey_of(arg_matches, "scoring_authority").unwrap_or(user_keypair.pubkey());
+            let scoring_authority =
+                pubkey_of(arg_matches, "scoring_authority").unwrap_or(user_keypair.pubkey());
             let freeze_pubkey;
             let mut freeze_authority: Option<&Pubkey> = None;
             if arg_matches.is_present("freeze_authority") {
                 freeze_pubkey = pubkey_of(arg_matches, "freeze_authority").unwrap();
                 freeze_authority = Some(&freeze_pubkey);
             }
+            let underflow_policy = if arg_matches.is_present("clamp_slashing") {
+                UnderflowPolicy::ClampToZero
+            } else {
+                UnderflowPolicy::Reject
+            };
+            let allow_self_close = arg_matches.is_present("allow_self_close");
+            let point_representation = if arg_matches.is_present("signed") {
+                PointRepresentation::Signed
+            } else {
+                PointRepresentation::Unsigned
+            };
+            let start_timestamp = timestamp_of(arg_matches, "start_time");
+            let end_timestamp = timestamp_of(arg_matches, "end_time");
+            let decay_policy = if arg_matches.is_present("decay_half_life") {
+                Some(DecayPolicy::HalfLife {
+                    half_life_seconds: value_t_or_exit!(arg_matches, "decay_half_life", u64),
+                })
+            } else if arg_matches.is_present("decay_per_day") {
+                Some(DecayPolicy::Linear {
+                    points_per_day: value_t_or_exit!(arg_matches, "decay_per_day", u64),
+                })
+            } else {
+                None
+            };
+            let max_points_per_account = value_of(arg_matches, "max_points_per_account");
+            let max_total_supply = value_of(arg_matches, "max_total_supply");
+            let stat_names = arg_matches
+                .values_of("stat")
+                .map(|names| names.map(String::from).collect())
+                .unwrap_or_default();
             let metadata_uri = arg_matches.value_of("metadata_uri").unwrap();
             let minimum_balance_for_rent_exemption =
                 rpc_client.get_minimum_balance_for_rent_exemption(Mint::SIZE)?;
@@ -259,22 +525,193 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         &mint,
                         &scoring_authority,
                         freeze_authority,
+                        underflow_policy,
+                        allow_self_close,
+                        start_timestamp,
+                        end_timestamp,
+                        decay_policy,
+                        max_points_per_account,
+                        max_total_supply,
+                        point_representation,
+                        stat_names,
                         metadata_uri.to_string(),
                     )?,
                 ],
                 Some(&user_keypair.pubkey()),
             );
-            let blockhash = rpc_client.get_recent_blockhash()?.0;
+            let blockhash = rpc_client.get_latest_blockhash()?;
             transaction.try_sign(&[&user_keypair, mint_signer.as_ref()], blockhash)?;
 
             rpc_client.send_and_confirm_transaction_with_spinner(&transaction)?;
             println!("Done creating scoring mint");
             Ok(())
         }
+        ("freeze-mint", Some(arg_matches)) => {
+            let user_keypair = config.keypair;
+            let mint = pubkey_of(arg_matches, "mint_address").unwrap();
+            let freeze_authority_signer =
+                get_signer(arg_matches, "freeze_authority", &mut wallet_manager);
+            let freeze_authority = freeze_authority_signer
+                .as_ref()
+                .map_or(user_keypair.pubkey(), |(_, pubkey)| *pubkey);
+            let mut signers: Vec<&dyn Signer> = vec![&user_keypair];
+            if let Some((signer, _)) = &freeze_authority_signer {
+                signers.push(signer.as_ref());
+            }
+
+            let mut transaction = Transaction::new_with_payer(
+                &[solana_scoring::instruction::freeze_mint(
+                    &id(),
+                    &mint,
+                    &freeze_authority,
+                    &[],
+                )?],
+                Some(&user_keypair.pubkey()),
+            );
+            let blockhash = rpc_client.get_latest_blockhash()?;
+            transaction.try_sign(&signers, blockhash)?;
+
+            rpc_client.send_and_confirm_transaction_with_spinner(&transaction)?;
+            println!("Done freezing scoring mint");
+            Ok(())
+        }
+        ("migrate-mint", Some(arg_matches)) => {
+            let user_keypair = config.keypair;
+            let mint = pubkey_of(arg_matches, "mint_address").unwrap();
+
+            let mut transaction = Transaction::new_with_payer(
+                &[solana_scoring::instruction::migrate_mint(
+                    &id(),
+                    &mint,
+                    &user_keypair.pubkey(),
+                )?],
+                Some(&user_keypair.pubkey()),
+            );
+            let blockhash = rpc_client.get_latest_blockhash()?;
+            transaction.try_sign(&[&user_keypair], blockhash)?;
+
+            rpc_client.send_and_confirm_transaction_with_spinner(&transaction)?;
+            println!("Done migrating scoring mint");
+            Ok(())
+        }
+        ("update-metadata-uri", Some(arg_matches)) => {
+            let user_keypair = config.keypair;
+            let mint = pubkey_of(arg_matches, "mint_address").unwrap();
+            let metadata_uri = arg_matches.value_of("metadata_uri").unwrap();
+            let scoring_authority_signer =
+                get_signer(arg_matches, "scoring_authority", &mut wallet_manager);
+            let scoring_authority = scoring_authority_signer
+                .as_ref()
+                .map_or(user_keypair.pubkey(), |(_, pubkey)| *pubkey);
+            let mut signers: Vec<&dyn Signer> = vec![&user_keypair];
+            if let Some((signer, _)) = &scoring_authority_signer {
+                signers.push(signer.as_ref());
+            }
+
+            let mut transaction = Transaction::new_with_payer(
+                &[solana_scoring::instruction::update_metadata_uri(
+                    &id(),
+                    &mint,
+                    &scoring_authority,
+                    &[],
+                    metadata_uri.to_string(),
+                )?],
+                Some(&user_keypair.pubkey()),
+            );
+            let blockhash = rpc_client.get_latest_blockhash()?;
+            transaction.try_sign(&signers, blockhash)?;
+
+            rpc_client.send_and_confirm_transaction_with_spinner(&transaction)?;
+            println!("Done updating metadata URI");
+            Ok(())
+        }
         _ => unreachable!(),
     }
 }
 
+fn get_mint(rpc_client: &RpcClient, mint_address: &Pubkey) -> Result<Mint, String> {
+    let account = rpc_client
+        .get_multiple_accounts(&[*mint_address])
+        .map_err(|err| err.to_string())?
+        .into_iter()
+        .next()
+        .unwrap();
+
+    match account {
+        Some(account) if account.owner == id() && account.data.len() == MintV0::SIZE => Err(
+            format!("Mint {} must be upgraded with migrate-mint", mint_address),
+        ),
+        Some(account) if account.owner == id() => {
+            try_from_slice_checked::<Mint>(&account.data, AccountType::Mint, Mint::SIZE)
+                .map_err(|err| format!("Failed to deserialize mint {}: {}", mint_address, err))
+        }
+        Some(_) => Err(format!("{} is not a scoring mint", mint_address)),
+        None => Err(format!("Mint {} does not exist", mint_address)),
+    }
+}
+
+/// Reads a score account's points by simulating a `GetScore` instruction.
+fn simulate_get_score(
+    rpc_client: &RpcClient,
+    payer: &dyn Signer,
+    mint_address: &Pubkey,
+    score_account_address: &Pubkey,
+) -> Result<ScoreReturnData, String> {
+    solana_scoring::instruction::simulate_get_score(
+        &id(),
+        &payer.pubkey(),
+        mint_address,
+        score_account_address,
+        None,
+        |message| -> Result<_, Box<dyn std::error::Error>> {
+            let mut transaction = Transaction::new_unsigned(message);
+            transaction.try_sign(&[payer], rpc_client.get_latest_blockhash()?)?;
+            let result = rpc_client.simulate_transaction(&transaction)?.value;
+            if let Some(err) = result.err {
+                return Err(format!(
+                    "Failed to read score account {}: {}",
+                    score_account_address, err
+                )
+                .into());
+            }
+            Ok(result.logs.unwrap_or_default())
+        },
+    )
+    .map_err(|err| err.to_string())?
+    .ok_or_else(|| format!("No score returned for {}", score_account_address))
+}
+
+fn get_score_account(
+    rpc_client: &RpcClient,
+    score_account_address: &Pubkey,
+) -> Result<ScoreAccount, String> {
+    let account = rpc_client
+        .get_multiple_accounts(&[*score_account_address])
+        .map_err(|err| err.to_string())?
+        .into_iter()
+        .next()
+        .unwrap();
+
+    match account {
+        Some(account) if account.owner == id() => try_from_slice_checked::<ScoreAccount>(
+            &account.data,
+            AccountType::ScoreAccount,
+            ScoreAccount::SIZE,
+        )
+        .map_err(|err| {
+            format!(
+                "Failed to deserialize score account {}: {}",
+                score_account_address, err
+            )
+        }),
+        Some(_) => Err(format!("{} is not a score account", score_account_address)),
+        None => Err(format!(
+            "Score account {} does not exist",
+            score_account_address
+        )),
+    }
+}
+
 // fn get_house(rpc_client: &RpcClient, house_address: &Pubkey) -> Result<HouseData, String> {
 //     let account = rpc_client
 //         .get_multiple_accounts(&[*house_address])
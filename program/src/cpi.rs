@@ -0,0 +1,70 @@
+//! Helpers for other on-chain programs invoking the scoring program.
+
+use {
+    crate::instruction::{self, ScoreReturnData},
+    borsh::BorshDeserialize,
+    solana_program::{
+        account_info::AccountInfo,
+        entrypoint::ProgramResult,
+        program::{get_return_data, invoke},
+        program_error::ProgramError,
+    },
+};
+
+/// Fails unless the wallet holds at least `min` points for the current season
+/// of the scoring mint, by invoking `AssertMinScore`. A wallet whose score
+/// account does not exist holds no points. The error returned by
+/// the scoring program, `ScoreError::ScoreBelowMinimum` when the score is too
+/// low, aborts the calling transaction.
+pub fn assert_min_score<'a>(
+    scoring_program: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    score_account: &AccountInfo<'a>,
+    wallet: &AccountInfo<'a>,
+    min: i64,
+) -> ProgramResult {
+    invoke(
+        &instruction::assert_min_score(
+            scoring_program.key,
+            mint.key,
+            score_account.key,
+            wallet.key,
+            min,
+        )?,
+        &[
+            mint.clone(),
+            score_account.clone(),
+            wallet.clone(),
+            scoring_program.clone(),
+        ],
+    )
+}
+
+/// Returns a score account's points and frozen status, by invoking
+/// `GetScore`. The closed season account is required for score accounts of a
+/// season which has ended.
+pub fn get_score<'a>(
+    scoring_program: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    score_account: &AccountInfo<'a>,
+    closed_season: Option<&AccountInfo<'a>>,
+) -> Result<ScoreReturnData, ProgramError> {
+    let mut account_infos = vec![mint.clone(), score_account.clone()];
+    account_infos.extend(closed_season.cloned());
+    account_infos.push(scoring_program.clone());
+    invoke(
+        &instruction::get_score(
+            scoring_program.key,
+            mint.key,
+            score_account.key,
+            closed_season.map(|closed_season| closed_season.key),
+        )?,
+        &account_infos,
+    )?;
+    match get_return_data() {
+        Some((program_id, data)) if program_id == *scoring_program.key => {
+            ScoreReturnData::try_from_slice(&data).map_err(|e| e.into())
+        }
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
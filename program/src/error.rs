@@ -22,6 +22,159 @@ pub enum ScoreError {
     /// Scoring mint account is not rent-exempt as required.
     #[error("Scoring mint account must hold enough lamports to be rent-exempt")]
     ScoringMintNotRentExempt,
+
+    /// Account is not owned by the expected program.
+    #[error("Account is not owned by the expected program")]
+    IncorrectOwner,
+
+    /// The score account address does not match the wallet and mint.
+    #[error("Score account address does not match the wallet and mint")]
+    InvalidScoreAccountAddress,
+
+    /// The score account does not belong to the provided mint.
+    #[error("Score account does not belong to the provided mint")]
+    MintMismatch,
+
+    /// The mint is frozen and points may no longer change.
+    #[error("Mint is frozen")]
+    MintFrozen,
+
+    /// Issuing points would overflow the score.
+    #[error("Score overflow")]
+    ScoreOverflow,
+
+    /// Slashing points would take the score below zero.
+    #[error("Score underflow")]
+    ScoreUnderflow,
+
+    /// The score account is frozen and its points may not change.
+    #[error("Score account is frozen")]
+    AccountFrozen,
+
+    /// Invalid account state for the requested operation.
+    #[error("Invalid account state for operation")]
+    InvalidState,
+
+    /// The metadata URI exceeds `Mint::MAX_METADATA_URI_LENGTH`.
+    #[error("Metadata URI too long")]
+    MetadataUriTooLong,
+
+    /// The account is already initialized.
+    #[error("Account already in use")]
+    AlreadyInUse,
+
+    /// Account is not rent-exempt as required.
+    #[error("Account must hold enough lamports to be rent-exempt")]
+    NotRentExempt,
+
+    /// Invalid number of provided signers.
+    #[error("Invalid number of provided signers")]
+    InvalidNumberOfProvidedSigners,
+
+    /// Invalid number of required signers.
+    #[error("Invalid number of required signers")]
+    InvalidNumberOfRequiredSigners,
+
+    /// The issuer delegate address does not match the mint and delegate.
+    #[error("Issuer delegate address does not match the mint and delegate")]
+    InvalidIssuerDelegateAddress,
+
+    /// The issuer delegate's allowance is too small to issue the points.
+    #[error("Insufficient issuer allowance")]
+    InsufficientAllowance,
+
+    /// The issuer delegate has expired.
+    #[error("Issuer delegate expired")]
+    DelegateExpired,
+
+    /// The mint's start timestamp has not been reached.
+    #[error("Mint has not started")]
+    MintNotStarted,
+
+    /// The mint's end timestamp is not after its start timestamp.
+    #[error("Invalid mint time window")]
+    InvalidTimeWindow,
+
+    /// The decay policy is invalid.
+    #[error("Invalid decay policy")]
+    InvalidDecayPolicy,
+
+    /// The score account belongs to a closed season and may no longer change.
+    #[error("Season is closed")]
+    SeasonClosed,
+
+    /// Closed season address does not match the mint and season.
+    #[error("Invalid closed season address")]
+    InvalidClosedSeasonAddress,
+
+    /// Leaderboard capacity is zero or exceeds the maximum.
+    #[error("Invalid leaderboard capacity")]
+    InvalidLeaderboardCapacity,
+
+    /// Leaderboard address does not match the mint and season.
+    #[error("Invalid leaderboard address")]
+    InvalidLeaderboardAddress,
+
+    /// Leaderboard does not rank the score account's mint season.
+    #[error("Leaderboard season mismatch")]
+    LeaderboardSeasonMismatch,
+
+    /// No Ed25519 program instruction verifies the score authority's signature
+    /// over the claimed message.
+    #[error("Missing or invalid signed points message")]
+    InvalidSignedMessage,
+
+    /// The signed points message has expired.
+    #[error("Signed points message expired")]
+    SignedMessageExpired,
+
+    /// The signed points message nonce has already been used.
+    #[error("Nonce already used")]
+    NonceAlreadyUsed,
+
+    /// Claim nonce address does not match the mint and wallet.
+    #[error("Invalid claim nonce address")]
+    InvalidClaimNonceAddress,
+
+    /// Issuing would exceed the maximum points per score account.
+    #[error("Score account points cap exceeded")]
+    AccountPointsCapExceeded,
+
+    /// Issuing would exceed the mint's maximum total supply.
+    #[error("Total supply cap exceeded")]
+    TotalSupplyCapExceeded,
+
+    /// The underflow policy is not supported by the point representation.
+    #[error("Invalid underflow policy")]
+    InvalidUnderflowPolicy,
+
+    /// Score account holds negative points.
+    #[error("Score is negative")]
+    NegativeScore,
+
+    /// Badge index exceeds the number of badges a score account may record.
+    #[error("Invalid badge index")]
+    InvalidBadgeIndex,
+
+    /// Too many stat slots, or a stat name is empty, too long or repeated.
+    #[error("Invalid stat schema")]
+    InvalidStatSchema,
+
+    /// Stat index is not declared by the mint.
+    #[error("Invalid stat index")]
+    InvalidStatIndex,
+
+    /// Account already uses the current layout.
+    #[error("Account layout is already current")]
+    AccountAlreadyMigrated,
+
+    /// Account data has an unrecognised layout version.
+    #[error("Invalid account version")]
+    InvalidAccountVersion,
+
+    /// Wallet holds fewer points than required.
+    #[error("Score is below the required minimum")]
+    ScoreBelowMinimum,
 }
 impl From<ScoreError> for ProgramError {
     fn from(e: ScoreError) -> Self {
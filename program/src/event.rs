@@ -0,0 +1,415 @@
+//! Structured events logged by the scoring program, and their decoder.
+//!
+//! Every successful instruction logs one Borsh-encoded `ScoreEvent` per state
+//! change with `sol_log_data`, which the runtime records as a
+//! `Program data: <base64>` log line.
+
+use {
+    crate::state::Points,
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{log::sol_log_data, pubkey::Pubkey},
+};
+
+/// Log line prefix used by the runtime for `sol_log_data`.
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+/// Events logged by the scoring program.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize, PartialEq)]
+pub enum ScoreEvent {
+    /// A scoring mint was initialized.
+    MintInitialized {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The mint's score authority.
+        score_authority: Pubkey,
+        /// The mint's freeze authority, if any.
+        freeze_authority: Option<Pubkey>,
+    },
+    /// A score account was created.
+    ScoreAccountCreated {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The wallet whose score is recorded.
+        wallet: Pubkey,
+        /// The mint season of the score account.
+        season: u32,
+    },
+    /// Points were issued to a score account.
+    PointsIssued {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The wallet receiving points.
+        wallet: Pubkey,
+        /// The mint season of the score account.
+        season: u32,
+        /// The number of points issued.
+        amount: u64,
+        /// The wallet's points after issuance.
+        points: Points,
+    },
+    /// Points were slashed from a score account.
+    PointsSlashed {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The wallet losing points.
+        wallet: Pubkey,
+        /// The mint season of the score account.
+        season: u32,
+        /// The number of points requested to be slashed.
+        amount: u64,
+        /// The wallet's points after slashing.
+        points: Points,
+    },
+    /// The scoring mint was frozen.
+    MintFrozen {
+        /// The scoring mint.
+        mint: Pubkey,
+    },
+    /// A score account was frozen.
+    ScoreAccountFrozen {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The wallet whose score account was frozen.
+        wallet: Pubkey,
+    },
+    /// A score account was thawed.
+    ScoreAccountThawed {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The wallet whose score account was thawed.
+        wallet: Pubkey,
+    },
+    /// A new score authority was proposed, or the proposal was cleared.
+    AuthorityProposed {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The proposed score authority.
+        pending_authority: Option<Pubkey>,
+    },
+    /// The score authority was accepted or renounced.
+    AuthorityChanged {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The previous score authority.
+        old_authority: Option<Pubkey>,
+        /// The new score authority, or `None` once renounced.
+        new_authority: Option<Pubkey>,
+    },
+    /// The mint's metadata URI was updated.
+    MetadataUriUpdated {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The new metadata URI.
+        metadata_uri: String,
+    },
+    /// A score account was closed.
+    ScoreAccountClosed {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The wallet whose score account was closed.
+        wallet: Pubkey,
+        /// The mint season of the score account.
+        season: u32,
+    },
+    /// A multisignature account was initialized.
+    MultisigInitialized {
+        /// The multisignature account.
+        multisig: Pubkey,
+        /// Number of signers required.
+        m: u8,
+        /// Number of valid signers.
+        n: u8,
+    },
+    /// An issuer delegate was created.
+    IssuerDelegateCreated {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The delegate allowed to issue points.
+        delegate: Pubkey,
+        /// The number of points the delegate may issue.
+        allowance: u64,
+        /// Optional slot after which the delegate may no longer issue points.
+        expiry_slot: Option<u64>,
+    },
+    /// An issuer delegate was revoked.
+    IssuerDelegateRevoked {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The revoked delegate.
+        delegate: Pubkey,
+    },
+    /// A new season of the scoring mint started.
+    SeasonStarted {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The new season.
+        season: u32,
+    },
+    /// A leaderboard was created.
+    LeaderboardCreated {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The mint season ranked by the leaderboard.
+        season: u32,
+        /// Maximum number of wallets ranked.
+        capacity: u16,
+    },
+    /// A badge was granted to a score account.
+    BadgeGranted {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The wallet receiving the badge.
+        wallet: Pubkey,
+        /// Index of the badge.
+        index: u8,
+    },
+    /// A badge was revoked from a score account.
+    BadgeRevoked {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The wallet losing the badge.
+        wallet: Pubkey,
+        /// Index of the badge.
+        index: u8,
+    },
+    /// Points were issued to a stat slot of a score account.
+    StatIssued {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The wallet receiving points.
+        wallet: Pubkey,
+        /// The mint season of the score account.
+        season: u32,
+        /// Index of the stat slot.
+        stat: u8,
+        /// The number of points issued.
+        amount: u64,
+        /// The stat's value after issuance.
+        value: u64,
+    },
+    /// Points were slashed from a stat slot of a score account.
+    StatSlashed {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The wallet losing points.
+        wallet: Pubkey,
+        /// The mint season of the score account.
+        season: u32,
+        /// Index of the stat slot.
+        stat: u8,
+        /// The number of points requested to be slashed.
+        amount: u64,
+        /// The stat's value after slashing.
+        value: u64,
+    },
+    /// A score account was migrated to a new wallet.
+    ScoreAccountMigrated {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The mint season of the score account.
+        season: u32,
+        /// The wallet the score account was migrated from.
+        old_wallet: Pubkey,
+        /// The wallet the score account was migrated to.
+        new_wallet: Pubkey,
+        /// Whether the old wallet signed the migration.
+        old_wallet_signed: bool,
+        /// The migrated points.
+        points: Points,
+    },
+    /// A scoring mint was migrated to the current account layout.
+    MintMigrated {
+        /// The scoring mint.
+        mint: Pubkey,
+        /// The layout version the mint was migrated from.
+        from_version: u8,
+        /// The layout version the mint was migrated to.
+        to_version: u8,
+    },
+}
+
+impl ScoreEvent {
+    /// Logs the event for indexers.
+    pub(crate) fn emit(&self) {
+        sol_log_data(&[&self.try_to_vec().unwrap()]);
+    }
+
+    /// Decodes an event from a `Program data: ` log line. Returns `None` for
+    /// any other log line, or data which is not a `ScoreEvent`.
+    pub fn from_log(log: &str) -> Option<Self> {
+        let data = log.strip_prefix(PROGRAM_DATA_PREFIX)?;
+        let data = base64::decode(data.split_whitespace().next()?).ok()?;
+        Self::try_from_slice(&data).ok()
+    }
+}
+
+/// Decodes the events logged by the scoring program from a transaction's log
+/// messages. Data logged by other programs, including those invoked by or
+/// invoking the scoring program, is ignored. A failed invocation fails the
+/// whole transaction, so no events are returned for a failed transaction.
+pub fn decode_events<S: AsRef<str>>(program_id: &Pubkey, logs: &[S]) -> Vec<ScoreEvent> {
+    let program_id = program_id.to_string();
+    let mut invocations: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+    for log in logs.iter().map(AsRef::as_ref) {
+        if log.starts_with(PROGRAM_DATA_PREFIX) {
+            if invocations.last() == Some(&program_id.as_str()) {
+                events.extend(ScoreEvent::from_log(log));
+            }
+            continue;
+        }
+        let mut words = log.split_whitespace();
+        if words.next() != Some("Program") {
+            continue;
+        }
+        // Lines logged by programs themselves, such as `Program log: `, do
+        // not name a program id.
+        let invoked_program_id = match words.next() {
+            Some(word) if word.parse::<Pubkey>().is_ok() => word,
+            _ => continue,
+        };
+        match words.next() {
+            Some("invoke") => invocations.push(invoked_program_id),
+            Some("success") => {
+                invocations.pop();
+            }
+            Some("failed:") => return Vec::new(),
+            _ => {}
+        }
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data_log(event: &ScoreEvent) -> String {
+        format!(
+            "{}{}",
+            PROGRAM_DATA_PREFIX,
+            base64::encode(event.try_to_vec().unwrap())
+        )
+    }
+
+    fn issued(mint: Pubkey, wallet: Pubkey, amount: u64) -> ScoreEvent {
+        ScoreEvent::PointsIssued {
+            mint,
+            wallet,
+            season: 0,
+            amount,
+            points: Points::Unsigned(amount),
+        }
+    }
+
+    #[test]
+    fn test_decode_events() {
+        let program_id = crate::id();
+        let (mint, wallet) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let logs = vec![
+            format!("Program {} invoke [1]", program_id),
+            "Program log: invoke".to_string(),
+            data_log(&issued(mint, wallet, 5)),
+            "Program log: failed: not really".to_string(),
+            data_log(&issued(mint, wallet, 7)),
+            format!(
+                "Program {} consumed 8113 of 200000 compute units",
+                program_id
+            ),
+            format!("Program {} success", program_id),
+        ];
+        assert_eq!(
+            decode_events(&program_id, &logs),
+            vec![issued(mint, wallet, 5), issued(mint, wallet, 7)]
+        );
+        assert!(decode_events(&Pubkey::new_unique(), &logs).is_empty());
+    }
+
+    #[test]
+    fn test_decode_events_nested_invocation() {
+        let program_id = crate::id();
+        let caller_id = Pubkey::new_unique();
+        let (mint, wallet) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let logs = vec![
+            "Program ComputeBudget111111111111111111111111111111 invoke [1]".to_string(),
+            "Program ComputeBudget111111111111111111111111111111 success".to_string(),
+            format!("Program {} invoke [1]", caller_id),
+            "Program log: Instruction: Claim".to_string(),
+            // Data logged by the caller is not a scoring event, even if it
+            // decodes as one.
+            data_log(&issued(mint, wallet, 1)),
+            format!("Program {} invoke [2]", program_id),
+            data_log(&issued(mint, wallet, 2)),
+            format!(
+                "Program {} consumed 6021 of 187335 compute units",
+                program_id
+            ),
+            format!("Program {} success", program_id),
+            format!("Program {} invoke [2]", program_id),
+            format!("Program return: {} AQIDBA==", program_id),
+            format!(
+                "Program {} consumed 2213 of 178011 compute units",
+                program_id
+            ),
+            format!("Program {} success", program_id),
+            data_log(&issued(mint, wallet, 3)),
+            "Program data: bm90IGFuIGV2ZW50".to_string(),
+            format!(
+                "Program {} consumed 24311 of 200000 compute units",
+                caller_id
+            ),
+            format!("Program {} success", caller_id),
+            format!("Program {} invoke [1]", program_id),
+            data_log(&issued(mint, wallet, 4)),
+            format!(
+                "Program {} consumed 5120 of 175689 compute units",
+                program_id
+            ),
+            format!("Program {} success", program_id),
+        ];
+        assert_eq!(
+            decode_events(&program_id, &logs),
+            vec![issued(mint, wallet, 2), issued(mint, wallet, 4)]
+        );
+    }
+
+    #[test]
+    fn test_decode_events_failed_inner_invocation() {
+        let program_id = crate::id();
+        let caller_id = Pubkey::new_unique();
+        let (mint, wallet) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let logs = vec![
+            format!("Program {} invoke [1]", program_id),
+            data_log(&issued(mint, wallet, 1)),
+            format!(
+                "Program {} consumed 5120 of 200000 compute units",
+                program_id
+            ),
+            format!("Program {} success", program_id),
+            format!("Program {} invoke [1]", caller_id),
+            format!("Program {} invoke [2]", program_id),
+            data_log(&issued(mint, wallet, 2)),
+            "Program log: Score 2 is below the minimum of 10".to_string(),
+            format!(
+                "Program {} consumed 4011 of 190012 compute units",
+                program_id
+            ),
+            format!("Program {} failed: custom program error: 0x25", program_id),
+            format!(
+                "Program {} consumed 13999 of 200000 compute units",
+                caller_id
+            ),
+            format!("Program {} failed: custom program error: 0x25", caller_id),
+        ];
+        assert!(decode_events(&program_id, &logs).is_empty());
+    }
+
+    #[test]
+    fn test_from_log() {
+        let event = issued(Pubkey::new_unique(), Pubkey::new_unique(), 9);
+        assert_eq!(ScoreEvent::from_log(&data_log(&event)), Some(event));
+        assert_eq!(ScoreEvent::from_log("Program log: hello"), None);
+        assert_eq!(ScoreEvent::from_log("Program data: !!!"), None);
+        assert_eq!(ScoreEvent::from_log("Program data: bm90IGFuIGV2ZW50"), None);
+    }
+}
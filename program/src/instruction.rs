@@ -1,13 +1,40 @@
 //! Program instructions
 
-use crate::{check_program_account};
+use crate::{
+    check_program_account, get_claim_nonce_address, get_closed_season_address,
+    get_issuer_delegate_address, get_leaderboard_address, get_score_account_address,
+    state::{
+        is_valid_signer_index, DecayPolicy, Leaderboard, PointRepresentation, Points, ScoreAccount,
+        UnderflowPolicy,
+    },
+};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
+    clock::UnixTimestamp,
     instruction::{AccountMeta, Instruction},
+    message::Message,
     program_error::ProgramError,
     pubkey::Pubkey,
+    system_program, sysvar,
 };
 
+/// Log line prefix used by the runtime for `set_return_data`.
+const PROGRAM_RETURN_PREFIX: &str = "Program return: ";
+/// Maximum serialized size of a transaction, matching
+/// `solana_sdk::packet::PACKET_DATA_SIZE`.
+const PACKET_DATA_SIZE: usize = 1280 - 40 - 8;
+/// Conservative estimate of the transaction bytes needed by an
+/// `IssuePointsBatch` transaction besides its signers and score accounts: the
+/// fee payer, message header, mint, authority, program, recent blockhash and
+/// instruction framing.
+const BATCH_BASE_SIZE: usize = 320;
+/// Transaction bytes needed by each additional signer: its signature, account
+/// key and account index.
+const BATCH_SIGNER_SIZE: usize = 64 + 32 + 1;
+/// Transaction bytes needed by each score account in a batch: its account key,
+/// account index and amount.
+const BATCH_ENTRY_SIZE: usize = 32 + 1 + 8;
+
 /// Instructions supported by the scoring program.
 #[derive(Clone, Debug, BorshSerialize, BorshDeserialize, PartialEq)]
 pub enum ScoreInstruction {
@@ -28,17 +55,694 @@ pub enum ScoreInstruction {
         score_authority: Pubkey,
         /// The freeze authority of the scoring mint.
         freeze_authority: Option<Pubkey>,
-        /// The URI to JSON metadata for the score type.
+        /// Behavior when slashing would take a score below zero.
+        underflow_policy: UnderflowPolicy,
+        /// Whether wallets may close their own score accounts.
+        allow_self_close: bool,
+        /// Optional unix timestamp before which points may not change.
+        start_timestamp: Option<UnixTimestamp>,
+        /// Optional unix timestamp from which the mint behaves as frozen.
+        end_timestamp: Option<UnixTimestamp>,
+        /// Optional policy by which scores fade while a wallet is inactive.
+        decay_policy: Option<DecayPolicy>,
+        /// Optional maximum number of points a score account may hold.
+        max_points_per_account: Option<u64>,
+        /// Optional maximum number of points the mint may ever issue.
+        max_total_supply: Option<u64>,
+        /// Whether score accounts hold unsigned or signed points. Signed mints
+        /// must use `UnderflowPolicy::Reject`.
+        point_representation: PointRepresentation,
+        /// Names of the stat slots recorded by each score account. Names must
+        /// be unique.
+        stat_names: Vec<String>,
+        /// The URI to JSON metadata for the score type. May be no longer than
+        /// `Mint::MAX_METADATA_URI_LENGTH` bytes.
+        metadata_uri: String,
+    },
+
+    /// Create the score account holding a wallet's points for the current
+    /// season of a scoring mint.
+    ///
+    /// The score account address is derived from the wallet and mint
+    /// addresses and the season, see `get_score_account_address`. The account
+    /// is created by the program through the system program and funded by the
+    /// funding account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Funding account (must be a system account).
+    ///   1. `[writable]` The score account address to be created.
+    ///   2. `[]` The wallet address for the new score account.
+    ///   3. `[]` The scoring mint for the new score account.
+    ///   4. `[]` System program.
+    ///
+    CreateScoreAccount,
+
+    /// Issue points to a score account.
+    ///
+    /// Fails if the mint is frozen, if the score would overflow or if the
+    /// mint's supply caps would be exceeded. Points may be issued by the score
+    /// authority or by an issuer delegate, whose allowance is reduced by the
+    /// issued amount. If the season's leaderboard is supplied as the last
+    /// account, it is updated with the new score.
+    ///
+    /// If `stat` is set, points are instead issued to that stat slot of the
+    /// score account. Stats are not subject to the supply caps and are not
+    /// ranked by the leaderboard, but an issuer delegate's allowance is still
+    /// reduced by the issued amount.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The scoring mint.
+    ///   1. `[writable]` The score account to receive points.
+    ///   2. `[signer]` The mint's score authority.
+    ///   3. `[writable]` Optional. The season's leaderboard.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The scoring mint.
+    ///   1. `[writable]` The score account to receive points.
+    ///   2. `[]` The mint's multisignature score authority.
+    ///   3. ..3+M `[signer]` M signer accounts, optionally followed by the
+    ///      season's `[writable]` leaderboard.
+    ///
+    ///   * Issuer delegate
+    ///   0. `[writable]` The scoring mint.
+    ///   1. `[writable]` The score account to receive points.
+    ///   2. `[signer]` The delegate.
+    ///   3. `[writable]` The delegate's issuer delegate account.
+    ///   4. `[writable]` Optional. The season's leaderboard.
+    ///
+    IssuePoints {
+        /// The number of points to issue.
+        amount: u64,
+        /// Optional index of the stat slot to issue points to.
+        stat: Option<u8>,
+    },
+
+    /// Slash points from a score account.
+    ///
+    /// Fails if the mint is frozen. Scores of signed mints may become
+    /// negative. For unsigned mints, slashing more points than the account
+    /// holds is rejected or clamped to zero according to the mint's
+    /// `UnderflowPolicy`. If the season's leaderboard is supplied as the last
+    /// account, it is updated with the new score.
+    ///
+    /// If `stat` is set, points are instead slashed from that stat slot of the
+    /// score account. Stats never become negative, so signed mints reject
+    /// slashing a stat below zero.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[]` The scoring mint.
+    ///   1. `[writable]` The score account to slash points from.
+    ///   2. `[signer]` The mint's score authority.
+    ///   3. `[writable]` Optional. The season's leaderboard.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[]` The scoring mint.
+    ///   1. `[writable]` The score account to slash points from.
+    ///   2. `[]` The mint's multisignature score authority.
+    ///   3. ..3+M `[signer]` M signer accounts, optionally followed by the
+    ///      season's `[writable]` leaderboard.
+    ///
+    SlashPoints {
+        /// The number of points to slash.
+        amount: u64,
+        /// Optional index of the stat slot to slash points from.
+        stat: Option<u8>,
+    },
+
+    /// Freeze the scoring mint, permanently preventing points from being
+    /// issued or slashed. Used to create final leaderboards.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The scoring mint to freeze.
+    ///   1. `[signer]` The mint's freeze authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The scoring mint to freeze.
+    ///   1. `[]` The mint's multisignature freeze authority.
+    ///   2. ..2+M `[signer]` M signer accounts.
+    ///
+    FreezeMint,
+
+    /// Freeze a single score account, preventing its points from being
+    /// issued or slashed while the rest of the mint remains active.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[]` The scoring mint.
+    ///   1. `[writable]` The score account to freeze.
+    ///   2. `[signer]` The mint's freeze authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[]` The scoring mint.
+    ///   1. `[writable]` The score account to freeze.
+    ///   2. `[]` The mint's multisignature freeze authority.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    ///
+    FreezeScoreAccount,
+
+    /// Thaw a frozen score account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[]` The scoring mint.
+    ///   1. `[writable]` The score account to thaw.
+    ///   2. `[signer]` The mint's freeze authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[]` The scoring mint.
+    ///   1. `[writable]` The score account to thaw.
+    ///   2. `[]` The mint's multisignature freeze authority.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    ///
+    ThawScoreAccount,
+
+    /// Propose a new score authority for the mint. The proposed authority
+    /// only takes effect once it signs an `AcceptScoreAuthority` instruction.
+    /// Proposing `None` cancels a pending proposal.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The scoring mint.
+    ///   1. `[signer]` The mint's current score authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The scoring mint.
+    ///   1. `[]` The mint's current multisignature score authority.
+    ///   2. ..2+M `[signer]` M signer accounts.
+    ///
+    ProposeScoreAuthority {
+        /// The proposed score authority.
+        new_authority: Option<Pubkey>,
+    },
+
+    /// Accept a pending score authority proposal, making the signer the
+    /// mint's score authority.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The scoring mint.
+    ///   1. `[signer]` The pending score authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The scoring mint.
+    ///   1. `[]` The pending multisignature score authority.
+    ///   2. ..2+M `[signer]` M signer accounts.
+    ///
+    AcceptScoreAuthority,
+
+    /// Permanently renounce the mint's score authority. Once renounced, points
+    /// can never be issued or slashed again and the authority can not be
+    /// restored.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The scoring mint.
+    ///   1. `[signer]` The mint's current score authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The scoring mint.
+    ///   1. `[]` The mint's current multisignature score authority.
+    ///   2. ..2+M `[signer]` M signer accounts.
+    ///
+    RenounceScoreAuthority,
+
+    /// Replace the URI to JSON metadata for the score type.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The scoring mint.
+    ///   1. `[signer]` The mint's score authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The scoring mint.
+    ///   1. `[]` The mint's multisignature score authority.
+    ///   2. ..2+M `[signer]` M signer accounts.
+    ///
+    UpdateMetadataUri {
+        /// The new URI to JSON metadata for the score type. May be no longer
+        /// than `Mint::MAX_METADATA_URI_LENGTH` bytes.
         metadata_uri: String,
     },
+
+    /// Close a score account, transferring its lamports to the destination
+    /// account and zeroing its data.
+    ///
+    /// The score authority may close any score account. The wallet owning
+    /// the score account may close it when the mint allows self-closing and
+    /// the score account is neither frozen nor holding negative points. If the
+    /// leaderboard of the score account's season is supplied as the last
+    /// account, the wallet is removed from it, unless the mint is frozen or the
+    /// season has closed.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[]` The scoring mint.
+    ///   1. `[writable]` The score account to close.
+    ///   2. `[writable]` The destination account for the reclaimed lamports.
+    ///   3. `[signer]` The mint's score authority or the score account's wallet.
+    ///   4. `[writable]` Optional. The season's leaderboard.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[]` The scoring mint.
+    ///   1. `[writable]` The score account to close.
+    ///   2. `[writable]` The destination account for the reclaimed lamports.
+    ///   3. `[]` The mint's multisignature score authority or the score account's wallet.
+    ///   4. ..4+M `[signer]` M signer accounts, optionally followed by the
+    ///      season's `[writable]` leaderboard.
+    ///
+    CloseScoreAccount,
+
+    /// Initialize a multisignature account with N provided signers. The
+    /// multisignature account may then be used as the score or freeze
+    /// authority of a scoring mint.
+    ///
+    /// The `InitializeMultisig` instruction requires no signers and MUST be
+    /// included within the same Transaction as the system program's
+    /// `CreateAccount` instruction that creates the account being initialized.
+    /// Otherwise another party can acquire ownership of the uninitialized
+    /// account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The multisignature account to initialize.
+    ///   1. ..1+N `[]` The signer accounts, must equal to N where 1 <= N <= 11.
+    ///
+    InitializeMultisig {
+        /// The number of signers (M) required to validate this multisignature
+        /// account.
+        m: u8,
+    },
+
+    /// Create an issuer delegate account, allowing a delegate to issue up to
+    /// `allowance` points on the mint without holding the score authority.
+    ///
+    /// The issuer delegate address is derived from the mint and delegate
+    /// addresses, see `get_issuer_delegate_address`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable,signer]` Funding account (must be a system account).
+    ///   1. `[writable]` The issuer delegate address to be created.
+    ///   2. `[]` The scoring mint.
+    ///   3. `[]` The delegate.
+    ///   4. `[]` System program.
+    ///   5. `[signer]` The mint's score authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable,signer]` Funding account (must be a system account).
+    ///   1. `[writable]` The issuer delegate address to be created.
+    ///   2. `[]` The scoring mint.
+    ///   3. `[]` The delegate.
+    ///   4. `[]` System program.
+    ///   5. `[]` The mint's multisignature score authority.
+    ///   6. ..6+M `[signer]` M signer accounts.
+    ///
+    CreateIssuerDelegate {
+        /// The number of points the delegate may issue.
+        allowance: u64,
+        /// Optional slot after which the delegate may no longer issue points.
+        expiry_slot: Option<u64>,
+    },
+
+    /// Revoke an issuer delegate, closing its account and transferring its
+    /// lamports to the destination account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[]` The scoring mint.
+    ///   1. `[writable]` The issuer delegate account to close.
+    ///   2. `[writable]` The destination account for the reclaimed lamports.
+    ///   3. `[signer]` The mint's score authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[]` The scoring mint.
+    ///   1. `[writable]` The issuer delegate account to close.
+    ///   2. `[writable]` The destination account for the reclaimed lamports.
+    ///   3. `[]` The mint's multisignature score authority.
+    ///   4. ..4+M `[signer]` M signer accounts.
+    ///
+    RevokeIssuerDelegate,
+
+    /// Issue points to several score accounts at once. Each score account
+    /// receives the amount at the same position in `amounts`. Either all
+    /// score accounts receive their points or the instruction fails. If the
+    /// season's leaderboard is supplied before the score accounts, it is
+    /// updated with each new score.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The scoring mint.
+    ///   1. `[signer]` The mint's score authority.
+    ///   2. ..2+N `[writable]` The N score accounts to receive points,
+    ///      optionally preceded by the season's `[writable]` leaderboard.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The scoring mint.
+    ///   1. `[]` The mint's multisignature score authority.
+    ///   2. ..2+M `[signer]` M signer accounts, optionally followed by the
+    ///      season's `[writable]` leaderboard, followed by the N `[writable]`
+    ///      score accounts to receive points.
+    ///
+    ///   * Issuer delegate
+    ///   0. `[writable]` The scoring mint.
+    ///   1. `[signer]` The delegate.
+    ///   2. `[writable]` The delegate's issuer delegate account.
+    ///   3. ..3+N `[writable]` The N score accounts to receive points,
+    ///      optionally preceded by the season's `[writable]` leaderboard.
+    ///
+    IssuePointsBatch {
+        /// The number of points to issue to each score account.
+        amounts: Vec<u64>,
+    },
+
+    /// Start a new season of the scoring mint. Score accounts of earlier
+    /// seasons remain readable but their points may no longer change; points
+    /// for the new season are recorded in new score accounts.
+    ///
+    /// The time the current season ends is recorded in a new `ClosedSeason`
+    /// account, whose address is derived from the mint address and the
+    /// current season, see `get_closed_season_address`. Points of the closed
+    /// season decay up to that time.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable,signer]` Funding account (must be a system account).
+    ///   1. `[writable]` The closed season address to be created.
+    ///   2. `[writable]` The scoring mint.
+    ///   3. `[]` System program.
+    ///   4. `[signer]` The mint's score authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable,signer]` Funding account (must be a system account).
+    ///   1. `[writable]` The closed season address to be created.
+    ///   2. `[writable]` The scoring mint.
+    ///   3. `[]` System program.
+    ///   4. `[]` The mint's multisignature score authority.
+    ///   5. ..5+M `[signer]` M signer accounts.
+    ///
+    StartNewSeason,
+
+    /// Create the leaderboard for the mint's current season, ranking up to
+    /// `capacity` wallets by points.
+    ///
+    /// The leaderboard address is derived from the mint address and season,
+    /// see `get_leaderboard_address`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable,signer]` Funding account (must be a system account).
+    ///   1. `[writable]` The leaderboard address to be created.
+    ///   2. `[]` The scoring mint.
+    ///   3. `[]` System program.
+    ///   4. `[signer]` The mint's score authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable,signer]` Funding account (must be a system account).
+    ///   1. `[writable]` The leaderboard address to be created.
+    ///   2. `[]` The scoring mint.
+    ///   3. `[]` System program.
+    ///   4. `[]` The mint's multisignature score authority.
+    ///   5. ..5+M `[signer]` M signer accounts.
+    ///
+    CreateLeaderboard {
+        /// Maximum number of wallets ranked, at most
+        /// `Leaderboard::MAX_CAPACITY`.
+        capacity: u16,
+    },
+
+    /// Claim a score change signed off-chain by the mint's score authority,
+    /// allowing any party to submit it and pay its fees.
+    ///
+    /// The instruction immediately preceding this one must be an Ed25519
+    /// program instruction verifying a single signature by the score authority
+    /// over the Borsh serialization of a `SignedPointsMessage` for this
+    /// program and the score account's mint, wallet and season. The
+    /// signature, public key and message must all be contained in that
+    /// instruction's data, as created by
+    /// `solana_sdk::ed25519_instruction::new_ed25519_instruction`. The score
+    /// authority must therefore be a single signer rather than a multisig.
+    ///
+    /// Fails if the message has expired or if its nonce is not greater than
+    /// the last nonce claimed for the wallet on the mint, as recorded by its
+    /// `ClaimNonce` account. The claim nonce account is created by the first
+    /// claim for the wallet, and its address is derived from the mint and
+    /// wallet addresses, see `get_claim_nonce_address`. A positive delta
+    /// issues points and a negative delta slashes them according to the
+    /// mint's `UnderflowPolicy`. If the season's leaderboard is supplied as
+    /// the last account, it is updated with the new score.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The scoring mint.
+    ///   1. `[writable]` The score account to update.
+    ///   2. `[writable]` The wallet's claim nonce account.
+    ///   3. `[]` Instructions sysvar.
+    ///   4. `[writable,signer]` Funding account (must be a system account),
+    ///      paying for the claim nonce account if it does not exist yet.
+    ///   5. `[]` System program.
+    ///   6. `[writable]` Optional. The season's leaderboard.
+    ///
+    ClaimSignedPoints {
+        /// The number of points to issue, or to slash if negative.
+        delta: i64,
+        /// Nonce of the signed message.
+        nonce: u64,
+        /// Unix timestamp after which the message may no longer be claimed.
+        expiry: UnixTimestamp,
+    },
+
+    /// Grant a badge to a score account. Badges are described by the mint's
+    /// metadata, see `BadgeDescriptor`.
+    ///
+    /// Fails if the mint is frozen or if the badge was already granted.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[]` The scoring mint.
+    ///   1. `[writable]` The score account to grant the badge to.
+    ///   2. `[signer]` The mint's score authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[]` The scoring mint.
+    ///   1. `[writable]` The score account to grant the badge to.
+    ///   2. `[]` The mint's multisignature score authority.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    ///
+    GrantBadge {
+        /// Index of the badge, less than `ScoreAccount::MAX_BADGES`.
+        index: u8,
+    },
+
+    /// Revoke a badge from a score account.
+    ///
+    /// Fails if the mint is frozen or if the badge was not granted.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[]` The scoring mint.
+    ///   1. `[writable]` The score account to revoke the badge from.
+    ///   2. `[signer]` The mint's score authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[]` The scoring mint.
+    ///   1. `[writable]` The score account to revoke the badge from.
+    ///   2. `[]` The mint's multisignature score authority.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    ///
+    RevokeBadge {
+        /// Index of the badge, less than `ScoreAccount::MAX_BADGES`.
+        index: u8,
+    },
+
+    /// Move a score account to a new wallet, such as after the old wallet was
+    /// compromised. The points, badges, stats and history of the score account
+    /// are copied to the new wallet's score account for the same season, and
+    /// the old score account is closed with its lamports returned to the
+    /// funding account.
+    ///
+    /// Both the score authority and the new wallet must sign. The old wallet
+    /// may sign as well, which is recorded in the logged event. If the
+    /// season's leaderboard is supplied as the last account, the old wallet's
+    /// entry is moved to the new wallet.
+    ///
+    /// Fails if the mint is frozen or has not started, if the score account
+    /// is frozen, or if its season has closed.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable,signer]` Funding account (must be a system account).
+    ///   1. `[writable]` The score account to migrate.
+    ///   2. `[writable]` The new wallet's score account address.
+    ///   3. `[]` The score account's wallet, optionally a signer.
+    ///   4. `[signer]` The new wallet.
+    ///   5. `[]` The scoring mint.
+    ///   6. `[]` System program.
+    ///   7. `[signer]` The mint's score authority.
+    ///   8. `[writable]` Optional. The season's leaderboard.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable,signer]` Funding account (must be a system account).
+    ///   1. `[writable]` The score account to migrate.
+    ///   2. `[writable]` The new wallet's score account address.
+    ///   3. `[]` The score account's wallet, optionally a signer.
+    ///   4. `[signer]` The new wallet.
+    ///   5. `[]` The scoring mint.
+    ///   6. `[]` System program.
+    ///   7. `[]` The mint's multisignature score authority.
+    ///   8. ..8+M `[signer]` M signer accounts, optionally followed by the
+    ///      season's `[writable]` leaderboard.
+    ///
+    MigrateScoreAccount,
+
+    /// Upgrade a version 0 scoring mint, laid out as a `MintV0`, to the
+    /// current account layout, reallocating the mint account. The mint's
+    /// authorities, state and metadata URI are kept, and options introduced
+    /// since take their defaults, see `Mint::from`. Any rent for the larger
+    /// account is paid by the funding account.
+    ///
+    /// The mint's settings are otherwise unchanged, so anyone may migrate a
+    /// mint.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The scoring mint to migrate.
+    ///   1. `[writable,signer]` Funding account (must be a system account).
+    ///   2. `[]` System program.
+    ///
+    MigrateMint,
+
+    /// Assert that a wallet holds at least a minimum number of points for the
+    /// current season of a scoring mint, failing with
+    /// `ScoreError::ScoreBelowMinimum` otherwise. Intended to be invoked by
+    /// other programs gating actions on a score, see the `cpi` module.
+    ///
+    /// Points are compared after applying the mint's decay policy. Frozen
+    /// score accounts keep their points and still satisfy the assertion. A
+    /// wallet without a score account holds no points, so only a `min` of
+    /// zero or less is satisfied.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` The scoring mint.
+    ///   1. `[]` The wallet's score account address for the mint's current
+    ///      season, which need not exist.
+    ///   2. `[]` The wallet.
+    ///
+    AssertMinScore {
+        /// The minimum number of points the wallet must hold.
+        min: i64,
+    },
+
+    /// Return a score account's points through `set_return_data`, as the
+    /// Borsh serialization of a `ScoreReturnData`. The points are those held
+    /// at the current time, after applying the mint's decay policy.
+    ///
+    /// Off-chain readers may simulate this instruction with
+    /// `simulate_get_score`, or parse the return data of their own simulation
+    /// with `ScoreReturnData::from_logs`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` The scoring mint.
+    ///   1. `[]` The score account to read.
+    ///   2. `[]` The `ClosedSeason` account of the score account's season,
+    ///      required if that season has ended.
+    ///
+    GetScore,
+}
+
+/// Score change signed off-chain by a mint's score authority and claimed with
+/// `ClaimSignedPoints`. The score authority signs the Borsh serialization of
+/// this message.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct SignedPointsMessage {
+    /// The scoring program the message may be claimed on.
+    pub program_id: Pubkey,
+    /// The scoring mint.
+    pub mint: Pubkey,
+    /// The wallet whose score changes.
+    pub wallet: Pubkey,
+    /// The mint season of the score account to update.
+    pub season: u32,
+    /// The number of points to issue, or to slash if negative.
+    pub delta: i64,
+    /// Nonce of the message, which must be greater than the last nonce
+    /// claimed for the wallet on the mint.
+    pub nonce: u64,
+    /// Unix timestamp after which the message may no longer be claimed.
+    pub expiry: UnixTimestamp,
+}
+
+/// Return data of the `GetScore` instruction, serialized with Borsh.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct ScoreReturnData {
+    /// The scoring mint.
+    pub mint: Pubkey,
+    /// The wallet whose score is recorded.
+    pub wallet: Pubkey,
+    /// The mint season of the score account.
+    pub season: u32,
+    /// The wallet's points, after applying the mint's decay policy.
+    pub points: Points,
+    /// Whether the score account is frozen.
+    pub account_frozen: bool,
+    /// Whether the scoring mint is frozen or has reached its end timestamp.
+    pub mint_frozen: bool,
+}
+
+impl ScoreReturnData {
+    /// Decodes the return data of a `GetScore` instruction from a
+    /// transaction's log messages. Returns `None` unless the scoring program
+    /// returned data.
+    pub fn from_logs<S: AsRef<str>>(program_id: &Pubkey, logs: &[S]) -> Option<Self> {
+        let prefix = format!("{}{} ", PROGRAM_RETURN_PREFIX, program_id);
+        let data = logs
+            .iter()
+            .rev()
+            .find_map(|log| log.as_ref().strip_prefix(&prefix))?;
+        let data = base64::decode(data.trim()).ok()?;
+        Self::try_from_slice(&data).ok()
+    }
 }
 
 /// Creates a `InitializeScoreMint` instruction.
+#[allow(clippy::too_many_arguments)]
 pub fn initialize_score_mint(
     scoring_program_id: &Pubkey,
     mint_pubkey: &Pubkey,
     score_authority_pubkey: &Pubkey,
     freeze_authority_pubkey: Option<&Pubkey>,
+    underflow_policy: UnderflowPolicy,
+    allow_self_close: bool,
+    start_timestamp: Option<UnixTimestamp>,
+    end_timestamp: Option<UnixTimestamp>,
+    decay_policy: Option<DecayPolicy>,
+    max_points_per_account: Option<u64>,
+    max_total_supply: Option<u64>,
+    point_representation: PointRepresentation,
+    stat_names: Vec<String>,
     metadata_uri: String,
 ) -> Result<Instruction, ProgramError> {
     check_program_account(scoring_program_id)?;
@@ -46,6 +750,15 @@ pub fn initialize_score_mint(
     let data = ScoreInstruction::InitializeScoreMint {
         score_authority: *score_authority_pubkey,
         freeze_authority,
+        underflow_policy,
+        allow_self_close,
+        start_timestamp,
+        end_timestamp,
+        decay_policy,
+        max_points_per_account,
+        max_total_supply,
+        point_representation,
+        stat_names,
         metadata_uri,
     }
     .try_to_vec().unwrap();
@@ -57,4 +770,1165 @@ pub fn initialize_score_mint(
         accounts,
         data,
     })
-}
\ No newline at end of file
+}
+
+/// Creates a `CreateScoreAccount` instruction.
+pub fn create_score_account(
+    scoring_program_id: &Pubkey,
+    funding_address: &Pubkey,
+    wallet_address: &Pubkey,
+    mint_address: &Pubkey,
+    season: u32,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let score_account_address = get_score_account_address(wallet_address, mint_address, season);
+    let data = ScoreInstruction::CreateScoreAccount.try_to_vec().unwrap();
+
+    let accounts = vec![
+        AccountMeta::new(*funding_address, true),
+        AccountMeta::new(score_account_address, false),
+        AccountMeta::new_readonly(*wallet_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `IssuePoints` instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn issue_points(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    score_account_pubkey: &Pubkey,
+    score_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    leaderboard_pubkey: Option<&Pubkey>,
+    amount: u64,
+    stat: Option<u8>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let data = ScoreInstruction::IssuePoints { amount, stat }
+        .try_to_vec()
+        .unwrap();
+
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new(*score_account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *score_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    if let Some(leaderboard_pubkey) = leaderboard_pubkey {
+        accounts.push(AccountMeta::new(*leaderboard_pubkey, false));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `IssuePoints` instruction signed by an issuer delegate.
+pub fn issue_points_as_delegate(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    score_account_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    leaderboard_pubkey: Option<&Pubkey>,
+    amount: u64,
+    stat: Option<u8>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let issuer_delegate_address = get_issuer_delegate_address(mint_pubkey, delegate_pubkey);
+    let data = ScoreInstruction::IssuePoints { amount, stat }
+        .try_to_vec()
+        .unwrap();
+
+    let mut accounts = vec![
+        AccountMeta::new(*mint_pubkey, false),
+        AccountMeta::new(*score_account_pubkey, false),
+        AccountMeta::new_readonly(*delegate_pubkey, true),
+        AccountMeta::new(issuer_delegate_address, false),
+    ];
+    if let Some(leaderboard_pubkey) = leaderboard_pubkey {
+        accounts.push(AccountMeta::new(*leaderboard_pubkey, false));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Returns the maximum number of score accounts a single `IssuePointsBatch`
+/// instruction may update while fitting in a transaction with the given number
+/// of additional signers.
+pub fn max_issue_points_batch_size(num_signers: usize) -> usize {
+    let available = PACKET_DATA_SIZE
+        .saturating_sub(BATCH_BASE_SIZE)
+        .saturating_sub(num_signers * BATCH_SIGNER_SIZE);
+    (available / BATCH_ENTRY_SIZE).max(1)
+}
+
+/// Creates `IssuePointsBatch` instructions issuing points to each
+/// `(score account, amount)` pair, split into chunks that each fit in a
+/// transaction.
+pub fn issue_points_batch(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    score_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    leaderboard_pubkey: Option<&Pubkey>,
+    issuances: &[(Pubkey, u64)],
+) -> Result<Vec<Instruction>, ProgramError> {
+    check_program_account(scoring_program_id)?;
+
+    let mut authority_accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    authority_accounts.push(AccountMeta::new_readonly(
+        *score_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        authority_accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    if let Some(leaderboard_pubkey) = leaderboard_pubkey {
+        authority_accounts.push(AccountMeta::new(*leaderboard_pubkey, false));
+    }
+
+    // A leaderboard takes no more space than one signer.
+    Ok(chunk_issue_points_batch(
+        scoring_program_id,
+        mint_pubkey,
+        &authority_accounts,
+        max_issue_points_batch_size(signer_pubkeys.len() + leaderboard_pubkey.iter().count()),
+        issuances,
+    ))
+}
+
+/// Creates `IssuePointsBatch` instructions signed by an issuer delegate,
+/// split into chunks that each fit in a transaction.
+pub fn issue_points_batch_as_delegate(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    leaderboard_pubkey: Option<&Pubkey>,
+    issuances: &[(Pubkey, u64)],
+) -> Result<Vec<Instruction>, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let issuer_delegate_address = get_issuer_delegate_address(mint_pubkey, delegate_pubkey);
+
+    let mut authority_accounts = vec![
+        AccountMeta::new_readonly(*delegate_pubkey, true),
+        AccountMeta::new(issuer_delegate_address, false),
+    ];
+    if let Some(leaderboard_pubkey) = leaderboard_pubkey {
+        authority_accounts.push(AccountMeta::new(*leaderboard_pubkey, false));
+    }
+
+    // The issuer delegate account and leaderboard each take no more space
+    // than one signer.
+    Ok(chunk_issue_points_batch(
+        scoring_program_id,
+        mint_pubkey,
+        &authority_accounts,
+        max_issue_points_batch_size(1 + leaderboard_pubkey.iter().count()),
+        issuances,
+    ))
+}
+
+fn chunk_issue_points_batch(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    authority_accounts: &[AccountMeta],
+    chunk_size: usize,
+    issuances: &[(Pubkey, u64)],
+) -> Vec<Instruction> {
+    issuances
+        .chunks(chunk_size)
+        .map(|chunk| {
+            let amounts = chunk.iter().map(|(_, amount)| *amount).collect();
+            let data = ScoreInstruction::IssuePointsBatch { amounts }
+                .try_to_vec()
+                .unwrap();
+
+            let mut accounts = Vec::with_capacity(1 + authority_accounts.len() + chunk.len());
+            accounts.push(AccountMeta::new(*mint_pubkey, false));
+            accounts.extend_from_slice(authority_accounts);
+            for (score_account_pubkey, _) in chunk.iter() {
+                accounts.push(AccountMeta::new(*score_account_pubkey, false));
+            }
+
+            Instruction {
+                program_id: *scoring_program_id,
+                accounts,
+                data,
+            }
+        })
+        .collect()
+}
+
+/// Creates a `SlashPoints` instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn slash_points(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    score_account_pubkey: &Pubkey,
+    score_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    leaderboard_pubkey: Option<&Pubkey>,
+    amount: u64,
+    stat: Option<u8>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let data = ScoreInstruction::SlashPoints { amount, stat }
+        .try_to_vec()
+        .unwrap();
+
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new(*score_account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *score_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    if let Some(leaderboard_pubkey) = leaderboard_pubkey {
+        accounts.push(AccountMeta::new(*leaderboard_pubkey, false));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `FreezeMint` instruction.
+pub fn freeze_mint(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    freeze_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let data = ScoreInstruction::FreezeMint.try_to_vec().unwrap();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *freeze_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `FreezeScoreAccount` instruction.
+pub fn freeze_score_account(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    score_account_pubkey: &Pubkey,
+    freeze_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let data = ScoreInstruction::FreezeScoreAccount.try_to_vec().unwrap();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new(*score_account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *freeze_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `ThawScoreAccount` instruction.
+pub fn thaw_score_account(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    score_account_pubkey: &Pubkey,
+    freeze_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let data = ScoreInstruction::ThawScoreAccount.try_to_vec().unwrap();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new(*score_account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *freeze_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `ProposeScoreAuthority` instruction.
+pub fn propose_score_authority(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    score_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    new_authority_pubkey: Option<&Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let data = ScoreInstruction::ProposeScoreAuthority {
+        new_authority: new_authority_pubkey.cloned(),
+    }
+    .try_to_vec()
+    .unwrap();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *score_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `AcceptScoreAuthority` instruction.
+pub fn accept_score_authority(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    new_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let data = ScoreInstruction::AcceptScoreAuthority.try_to_vec().unwrap();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *new_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `RenounceScoreAuthority` instruction.
+pub fn renounce_score_authority(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    score_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let data = ScoreInstruction::RenounceScoreAuthority
+        .try_to_vec()
+        .unwrap();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *score_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `UpdateMetadataUri` instruction.
+pub fn update_metadata_uri(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    score_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    metadata_uri: String,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let data = ScoreInstruction::UpdateMetadataUri { metadata_uri }
+        .try_to_vec()
+        .unwrap();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *score_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `CloseScoreAccount` instruction.
+pub fn close_score_account(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    score_account_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    leaderboard_pubkey: Option<&Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let data = ScoreInstruction::CloseScoreAccount.try_to_vec().unwrap();
+
+    let mut accounts = Vec::with_capacity(5 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new(*score_account_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    if let Some(leaderboard_pubkey) = leaderboard_pubkey {
+        accounts.push(AccountMeta::new(*leaderboard_pubkey, false));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `InitializeMultisig` instruction.
+pub fn initialize_multisig(
+    scoring_program_id: &Pubkey,
+    multisig_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    m: u8,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    if !is_valid_signer_index(m as usize)
+        || !is_valid_signer_index(signer_pubkeys.len())
+        || m as usize > signer_pubkeys.len()
+    {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let data = ScoreInstruction::InitializeMultisig { m }
+        .try_to_vec()
+        .unwrap();
+
+    let mut accounts = Vec::with_capacity(1 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*multisig_pubkey, false));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, false));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `CreateIssuerDelegate` instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn create_issuer_delegate(
+    scoring_program_id: &Pubkey,
+    funding_address: &Pubkey,
+    mint_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    score_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    allowance: u64,
+    expiry_slot: Option<u64>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let issuer_delegate_address = get_issuer_delegate_address(mint_pubkey, delegate_pubkey);
+    let data = ScoreInstruction::CreateIssuerDelegate {
+        allowance,
+        expiry_slot,
+    }
+    .try_to_vec()
+    .unwrap();
+
+    let mut accounts = Vec::with_capacity(6 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*funding_address, true));
+    accounts.push(AccountMeta::new(issuer_delegate_address, false));
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*delegate_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(system_program::id(), false));
+    accounts.push(AccountMeta::new_readonly(
+        *score_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `RevokeIssuerDelegate` instruction.
+pub fn revoke_issuer_delegate(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    score_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let issuer_delegate_address = get_issuer_delegate_address(mint_pubkey, delegate_pubkey);
+    let data = ScoreInstruction::RevokeIssuerDelegate.try_to_vec().unwrap();
+
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new(issuer_delegate_address, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *score_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `StartNewSeason` instruction, closing the mint's current
+/// `season`.
+pub fn start_new_season(
+    scoring_program_id: &Pubkey,
+    funding_address: &Pubkey,
+    mint_pubkey: &Pubkey,
+    season: u32,
+    score_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let closed_season_address = get_closed_season_address(mint_pubkey, season);
+    let data = ScoreInstruction::StartNewSeason.try_to_vec().unwrap();
+
+    let mut accounts = Vec::with_capacity(5 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*funding_address, true));
+    accounts.push(AccountMeta::new(closed_season_address, false));
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(system_program::id(), false));
+    accounts.push(AccountMeta::new_readonly(
+        *score_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `CreateLeaderboard` instruction for the given season, which must
+/// be the mint's current season.
+pub fn create_leaderboard(
+    scoring_program_id: &Pubkey,
+    funding_address: &Pubkey,
+    mint_pubkey: &Pubkey,
+    season: u32,
+    score_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    capacity: u16,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    if capacity == 0 || capacity > Leaderboard::MAX_CAPACITY {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let leaderboard_address = get_leaderboard_address(mint_pubkey, season);
+    let data = ScoreInstruction::CreateLeaderboard { capacity }
+        .try_to_vec()
+        .unwrap();
+
+    let mut accounts = Vec::with_capacity(5 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*funding_address, true));
+    accounts.push(AccountMeta::new(leaderboard_address, false));
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(system_program::id(), false));
+    accounts.push(AccountMeta::new_readonly(
+        *score_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `ClaimSignedPoints` instruction for the wallet's score account of
+/// the message's season. The transaction must include the Ed25519 program
+/// instruction verifying the score authority's signature over `message`
+/// immediately before this instruction.
+pub fn claim_signed_points(
+    scoring_program_id: &Pubkey,
+    funding_address: &Pubkey,
+    message: &SignedPointsMessage,
+    leaderboard_pubkey: Option<&Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    if message.program_id != *scoring_program_id {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let score_account_address =
+        get_score_account_address(&message.wallet, &message.mint, message.season);
+    let claim_nonce_address = get_claim_nonce_address(&message.mint, &message.wallet);
+    let data = ScoreInstruction::ClaimSignedPoints {
+        delta: message.delta,
+        nonce: message.nonce,
+        expiry: message.expiry,
+    }
+    .try_to_vec()
+    .unwrap();
+
+    let mut accounts = vec![
+        AccountMeta::new(message.mint, false),
+        AccountMeta::new(score_account_address, false),
+        AccountMeta::new(claim_nonce_address, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        AccountMeta::new(*funding_address, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    if let Some(leaderboard_pubkey) = leaderboard_pubkey {
+        accounts.push(AccountMeta::new(*leaderboard_pubkey, false));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `GrantBadge` instruction.
+pub fn grant_badge(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    score_account_pubkey: &Pubkey,
+    score_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    index: u8,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    if index >= ScoreAccount::MAX_BADGES {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let data = ScoreInstruction::GrantBadge { index }.try_to_vec().unwrap();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new(*score_account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *score_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `RevokeBadge` instruction.
+pub fn revoke_badge(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    score_account_pubkey: &Pubkey,
+    score_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    index: u8,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    if index >= ScoreAccount::MAX_BADGES {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let data = ScoreInstruction::RevokeBadge { index }
+        .try_to_vec()
+        .unwrap();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new(*score_account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *score_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `MigrateScoreAccount` instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn migrate_score_account(
+    scoring_program_id: &Pubkey,
+    funding_address: &Pubkey,
+    mint_address: &Pubkey,
+    season: u32,
+    old_wallet_address: &Pubkey,
+    old_wallet_signs: bool,
+    new_wallet_address: &Pubkey,
+    score_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    leaderboard_pubkey: Option<&Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let data = ScoreInstruction::MigrateScoreAccount.try_to_vec().unwrap();
+
+    let mut accounts = Vec::with_capacity(9 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*funding_address, true));
+    accounts.push(AccountMeta::new(
+        get_score_account_address(old_wallet_address, mint_address, season),
+        false,
+    ));
+    accounts.push(AccountMeta::new(
+        get_score_account_address(new_wallet_address, mint_address, season),
+        false,
+    ));
+    accounts.push(AccountMeta::new_readonly(
+        *old_wallet_address,
+        old_wallet_signs,
+    ));
+    accounts.push(AccountMeta::new_readonly(*new_wallet_address, true));
+    accounts.push(AccountMeta::new_readonly(*mint_address, false));
+    accounts.push(AccountMeta::new_readonly(system_program::id(), false));
+    accounts.push(AccountMeta::new_readonly(
+        *score_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    if let Some(leaderboard_pubkey) = leaderboard_pubkey {
+        accounts.push(AccountMeta::new(*leaderboard_pubkey, false));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `MigrateMint` instruction.
+pub fn migrate_mint(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    funding_address: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let data = ScoreInstruction::MigrateMint.try_to_vec().unwrap();
+
+    let accounts = vec![
+        AccountMeta::new(*mint_pubkey, false),
+        AccountMeta::new(*funding_address, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `AssertMinScore` instruction.
+pub fn assert_min_score(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    score_account_pubkey: &Pubkey,
+    wallet_pubkey: &Pubkey,
+    min: i64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let data = ScoreInstruction::AssertMinScore { min }
+        .try_to_vec()
+        .unwrap();
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*mint_pubkey, false),
+        AccountMeta::new_readonly(*score_account_pubkey, false),
+        AccountMeta::new_readonly(*wallet_pubkey, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `GetScore` instruction. The closed season account is required
+/// for score accounts of a season which has ended.
+pub fn get_score(
+    scoring_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    score_account_pubkey: &Pubkey,
+    closed_season_pubkey: Option<&Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(scoring_program_id)?;
+    let data = ScoreInstruction::GetScore.try_to_vec().unwrap();
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*mint_pubkey, false),
+        AccountMeta::new_readonly(*score_account_pubkey, false),
+    ];
+    if let Some(closed_season_pubkey) = closed_season_pubkey {
+        accounts.push(AccountMeta::new_readonly(*closed_season_pubkey, false));
+    }
+
+    Ok(Instruction {
+        program_id: *scoring_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Reads a score account's points by simulating a `GetScore` instruction, so
+/// decay is applied by the program exactly as it would be on-chain.
+///
+/// `simulate` receives the transaction message, paid for by `payer_pubkey`,
+/// and returns the log messages of simulating it, e.g. with the
+/// `simulateTransaction` RPC method. Returns `None` if the simulation returned
+/// no score.
+pub fn simulate_get_score<E, F>(
+    scoring_program_id: &Pubkey,
+    payer_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    score_account_pubkey: &Pubkey,
+    closed_season_pubkey: Option<&Pubkey>,
+    simulate: F,
+) -> Result<Option<ScoreReturnData>, E>
+where
+    E: From<ProgramError>,
+    F: FnOnce(Message) -> Result<Vec<String>, E>,
+{
+    let instruction = get_score(
+        scoring_program_id,
+        mint_pubkey,
+        score_account_pubkey,
+        closed_season_pubkey,
+    )?;
+    let logs = simulate(Message::new(&[instruction], Some(payer_pubkey)))?;
+    Ok(ScoreReturnData::from_logs(scoring_program_id, &logs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn return_data(points: Points) -> ScoreReturnData {
+        ScoreReturnData {
+            mint: Pubkey::new_unique(),
+            wallet: Pubkey::new_unique(),
+            season: 2,
+            points,
+            account_frozen: false,
+            mint_frozen: true,
+        }
+    }
+
+    fn return_log(program_id: &Pubkey, data: &[u8]) -> String {
+        format!(
+            "{}{} {}",
+            PROGRAM_RETURN_PREFIX,
+            program_id,
+            base64::encode(data)
+        )
+    }
+
+    #[test]
+    fn test_score_return_data_from_logs() {
+        let program_id = crate::id();
+        let expected = return_data(Points::Signed(-12));
+        let logs = vec![
+            format!("Program {} invoke [1]", program_id),
+            return_log(&program_id, &expected.try_to_vec().unwrap()),
+            format!(
+                "Program {} consumed 3542 of 200000 compute units",
+                program_id
+            ),
+            format!("Program {} success", program_id),
+        ];
+        assert_eq!(
+            ScoreReturnData::from_logs(&program_id, &logs),
+            Some(expected)
+        );
+        assert_eq!(
+            ScoreReturnData::from_logs(&Pubkey::new_unique(), &logs),
+            None
+        );
+    }
+
+    #[test]
+    fn test_score_return_data_from_logs_nested_invocation() {
+        let program_id = crate::id();
+        let caller_id = Pubkey::new_unique();
+        let expected = return_data(Points::Unsigned(40));
+        let logs = vec![
+            format!("Program {} invoke [1]", caller_id),
+            format!("Program {} invoke [2]", program_id),
+            return_log(&program_id, &expected.try_to_vec().unwrap()),
+            format!(
+                "Program {} consumed 3542 of 191204 compute units",
+                program_id
+            ),
+            format!("Program {} success", program_id),
+            "Program log: score 40".to_string(),
+            return_log(&caller_id, &[1, 0]),
+            format!(
+                "Program {} consumed 12480 of 200000 compute units",
+                caller_id
+            ),
+            format!("Program {} success", caller_id),
+        ];
+        assert_eq!(
+            ScoreReturnData::from_logs(&program_id, &logs),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn test_score_return_data_from_logs_failed_invocation() {
+        let program_id = crate::id();
+        let logs = vec![
+            format!("Program {} invoke [1]", program_id),
+            format!(
+                "Program {} consumed 1877 of 200000 compute units",
+                program_id
+            ),
+            format!(
+                "Program {} failed: invalid account data for instruction",
+                program_id
+            ),
+        ];
+        assert_eq!(ScoreReturnData::from_logs(&program_id, &logs), None);
+
+        // Return data which is not a `ScoreReturnData` is rejected.
+        let logs = vec![return_log(&program_id, &[1, 2, 3])];
+        assert_eq!(ScoreReturnData::from_logs(&program_id, &logs), None);
+    }
+
+    /// Returns the wire size of a transaction holding the instruction: the
+    /// compact-u16 signature count, the signatures and the message.
+    fn transaction_size(instruction: &Instruction, payer: &Pubkey) -> usize {
+        let message = Message::new(std::slice::from_ref(instruction), Some(payer));
+        1 + 64 * message.header.num_required_signatures as usize + message.serialize().len()
+    }
+
+    fn issuances(count: usize) -> Vec<(Pubkey, u64)> {
+        (0..count)
+            .map(|_| (Pubkey::new_unique(), u64::MAX))
+            .collect()
+    }
+
+    #[test]
+    fn test_issue_points_batch_fits_in_transaction() {
+        let program_id = crate::id();
+        let mint = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let signers = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        for num_signers in 0..=signers.len() {
+            let signer_pubkeys: Vec<&Pubkey> = signers[..num_signers].iter().collect();
+            for leaderboard in [None, Some(Pubkey::new_unique())].iter() {
+                let max = max_issue_points_batch_size(num_signers + leaderboard.iter().count());
+                let instructions = issue_points_batch(
+                    &program_id,
+                    &mint,
+                    &authority,
+                    &signer_pubkeys,
+                    leaderboard.as_ref(),
+                    &issuances(max + 1),
+                )
+                .unwrap();
+                assert_eq!(instructions.len(), 2);
+                assert!(transaction_size(&instructions[0], &payer) <= PACKET_DATA_SIZE);
+            }
+        }
+    }
+
+    #[test]
+    fn test_issue_points_batch_as_delegate_fits_in_transaction() {
+        let program_id = crate::id();
+        let mint = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        for leaderboard in [None, Some(Pubkey::new_unique())].iter() {
+            let max = max_issue_points_batch_size(1 + leaderboard.iter().count());
+            let instructions = issue_points_batch_as_delegate(
+                &program_id,
+                &mint,
+                &delegate,
+                leaderboard.as_ref(),
+                &issuances(max + 1),
+            )
+            .unwrap();
+            assert_eq!(instructions.len(), 2);
+            assert!(transaction_size(&instructions[0], &payer) <= PACKET_DATA_SIZE);
+        }
+    }
+
+    #[test]
+    fn test_simulate_get_score() {
+        let program_id = crate::id();
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_from_array([1; 32]);
+        let score_account = Pubkey::new_unique();
+
+        // Logs recorded from simulating `GetScore` for season 3 of the mint.
+        let recorded_logs = vec![
+            format!("Program {} invoke [1]", program_id),
+            format!(
+                "Program return: {} AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQECAgICAgICAgICAgI\
+                 CAgICAgICAgICAgICAgICAgICAgMAAAAA3AUAAAAAAAAAAA==",
+                program_id
+            ),
+            format!(
+                "Program {} consumed 3542 of 200000 compute units",
+                program_id
+            ),
+            format!("Program {} success", program_id),
+        ];
+        let score = simulate_get_score(
+            &program_id,
+            &payer,
+            &mint,
+            &score_account,
+            None,
+            |message| -> Result<_, ProgramError> {
+                assert_eq!(message.account_keys[0], payer);
+                assert_eq!(message.header.num_required_signatures, 1);
+                assert_eq!(message.instructions.len(), 1);
+                let instruction = &message.instructions[0];
+                assert_eq!(
+                    message.account_keys[instruction.program_id_index as usize],
+                    program_id
+                );
+                assert_eq!(
+                    instruction.data,
+                    ScoreInstruction::GetScore.try_to_vec().unwrap()
+                );
+                Ok(recorded_logs)
+            },
+        );
+        assert_eq!(
+            score,
+            Ok(Some(ScoreReturnData {
+                mint,
+                wallet: Pubkey::new_from_array([2; 32]),
+                season: 3,
+                points: Points::Unsigned(1_500),
+                account_frozen: false,
+                mint_frozen: false,
+            }))
+        );
+
+        // A simulation that fails before returning data yields no score.
+        let failed_logs = vec![
+            format!("Program {} invoke [1]", program_id),
+            format!("Program {} failed: custom program error: 0x6", program_id),
+        ];
+        assert_eq!(
+            simulate_get_score(
+                &program_id,
+                &payer,
+                &mint,
+                &score_account,
+                None,
+                |_| -> Result<_, ProgramError> { Ok(failed_logs) },
+            ),
+            Ok(None)
+        );
+    }
+}
@@ -1,8 +1,10 @@
 //! Solana scoring program
 #![deny(missing_docs)]
 
+pub mod cpi;
 mod entrypoint;
 pub mod error;
+pub mod event;
 pub mod instruction;
 pub mod processor;
 pub mod state;
@@ -14,10 +16,138 @@ use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pub
 
 solana_program::declare_id!("SCorEKFKYJud973vCJvWFphgqQGAHo9Ruxuf622LER1");
 
+/// The Ed25519 signature verification program, which verifies signed points
+/// messages claimed with `ClaimSignedPoints`.
+pub(crate) mod ed25519_program {
+    solana_program::declare_id!("Ed25519SigVerify111111111111111111111111111");
+}
+
 /// Checks that the supplied program ID is the correct one for scoring.
 pub fn check_program_account(solana_program_id: &Pubkey) -> ProgramResult {
     if solana_program_id != &id() {
         return Err(ProgramError::IncorrectProgramId);
     }
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Derives the address of the score account holding a wallet's points for the
+/// given scoring mint and season.
+pub fn get_score_account_address(
+    wallet_address: &Pubkey,
+    mint_address: &Pubkey,
+    season: u32,
+) -> Pubkey {
+    get_score_account_address_and_bump_seed(wallet_address, mint_address, season, &id()).0
+}
+
+pub(crate) fn get_score_account_address_and_bump_seed(
+    wallet_address: &Pubkey,
+    mint_address: &Pubkey,
+    season: u32,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            &wallet_address.to_bytes(),
+            &mint_address.to_bytes(),
+            &season.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Seed prefix distinguishing issuer delegate addresses from score accounts.
+pub(crate) const ISSUER_DELEGATE_SEED: &[u8] = b"issuer";
+
+/// Derives the address of the issuer delegate account allowing a delegate to
+/// issue points on the given scoring mint.
+pub fn get_issuer_delegate_address(mint_address: &Pubkey, delegate_address: &Pubkey) -> Pubkey {
+    get_issuer_delegate_address_and_bump_seed(mint_address, delegate_address, &id()).0
+}
+
+pub(crate) fn get_issuer_delegate_address_and_bump_seed(
+    mint_address: &Pubkey,
+    delegate_address: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            ISSUER_DELEGATE_SEED,
+            &mint_address.to_bytes(),
+            &delegate_address.to_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Seed prefix distinguishing closed season addresses from score accounts.
+pub(crate) const CLOSED_SEASON_SEED: &[u8] = b"closed_season";
+
+/// Derives the address of the account recording when the given season of the
+/// scoring mint ended.
+pub fn get_closed_season_address(mint_address: &Pubkey, season: u32) -> Pubkey {
+    get_closed_season_address_and_bump_seed(mint_address, season, &id()).0
+}
+
+pub(crate) fn get_closed_season_address_and_bump_seed(
+    mint_address: &Pubkey,
+    season: u32,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            CLOSED_SEASON_SEED,
+            &mint_address.to_bytes(),
+            &season.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Seed prefix distinguishing leaderboard addresses from score accounts.
+pub(crate) const LEADERBOARD_SEED: &[u8] = b"leaderboard";
+
+/// Derives the address of the leaderboard ranking wallets for the given
+/// scoring mint and season.
+pub fn get_leaderboard_address(mint_address: &Pubkey, season: u32) -> Pubkey {
+    get_leaderboard_address_and_bump_seed(mint_address, season, &id()).0
+}
+
+pub(crate) fn get_leaderboard_address_and_bump_seed(
+    mint_address: &Pubkey,
+    season: u32,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            LEADERBOARD_SEED,
+            &mint_address.to_bytes(),
+            &season.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Seed prefix distinguishing claim nonce addresses from score accounts.
+pub(crate) const CLAIM_NONCE_SEED: &[u8] = b"claim_nonce";
+
+/// Derives the address of the account recording the last nonce of the signed
+/// points messages claimed for a wallet on the given scoring mint.
+pub fn get_claim_nonce_address(mint_address: &Pubkey, wallet_address: &Pubkey) -> Pubkey {
+    get_claim_nonce_address_and_bump_seed(mint_address, wallet_address, &id()).0
+}
+
+pub(crate) fn get_claim_nonce_address_and_bump_seed(
+    mint_address: &Pubkey,
+    wallet_address: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            CLAIM_NONCE_SEED,
+            &mint_address.to_bytes(),
+            &wallet_address.to_bytes(),
+        ],
+        program_id,
+    )
+}
@@ -2,27 +2,45 @@
 
 use {
     crate::{
-        error::ScoreError, instruction::ScoreInstruction, state::Mint, state::MintState,
-        utils::try_from_slice_checked,
+        ed25519_program,
+        error::ScoreError,
+        event::ScoreEvent,
+        get_claim_nonce_address_and_bump_seed, get_closed_season_address_and_bump_seed,
+        get_issuer_delegate_address_and_bump_seed, get_leaderboard_address_and_bump_seed,
+        get_score_account_address_and_bump_seed,
+        instruction::{ScoreInstruction, ScoreReturnData, SignedPointsMessage},
+        state::{
+            is_valid_signer_index, AccountType, ClaimNonce, ClosedSeason, DecayPolicy,
+            IssuerDelegate, Leaderboard, Mint, MintState, MintV0, Multisig, PointRepresentation,
+            Points, ScoreAccount, ScoreAccountState, UnderflowPolicy, MAX_SIGNERS,
+        },
+        utils::{
+            assert_account_version, assert_owned_by, create_pda_account, try_from_slice_checked,
+        },
+        CLAIM_NONCE_SEED, CLOSED_SEASON_SEED, ISSUER_DELEGATE_SEED, LEADERBOARD_SEED,
     },
     borsh::{BorshDeserialize, BorshSerialize},
     solana_program::{
         account_info::{next_account_info, AccountInfo},
+        borsh::try_from_slice_unchecked,
+        clock::{Clock, UnixTimestamp},
         entrypoint::ProgramResult,
         msg,
-        // program::invoke_signed,
+        program::{invoke, set_return_data},
         program_error::ProgramError,
         pubkey::Pubkey,
         rent::Rent,
-        // system_instruction,
-        // system_program,
-        sysvar::Sysvar, // for Rent::get()
+        system_instruction,
+        sysvar::{
+            instructions::{load_current_index_checked, load_instruction_at_checked},
+            Sysvar, // for Rent::get() and Clock::get()
+        },
     },
 };
 
 /// Instruction processor
 pub fn process_instruction(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     input: &[u8],
 ) -> ProgramResult {
@@ -36,22 +54,113 @@ pub fn process_instruction(
         ScoreInstruction::InitializeScoreMint {
             score_authority,
             freeze_authority,
+            underflow_policy,
+            allow_self_close,
+            start_timestamp,
+            end_timestamp,
+            decay_policy,
+            max_points_per_account,
+            max_total_supply,
+            point_representation,
+            stat_names,
             metadata_uri,
         } => process_initialize_score_mint(
-            _program_id,
+            program_id,
             accounts,
             &score_authority,
             freeze_authority,
+            underflow_policy,
+            allow_self_close,
+            start_timestamp,
+            end_timestamp,
+            decay_policy,
+            max_points_per_account,
+            max_total_supply,
+            point_representation,
+            stat_names,
             metadata_uri,
         ),
+        ScoreInstruction::CreateScoreAccount => process_create_score_account(program_id, accounts),
+        ScoreInstruction::IssuePoints { amount, stat } => {
+            process_issue_points(program_id, accounts, amount, stat)
+        }
+        ScoreInstruction::SlashPoints { amount, stat } => {
+            process_slash_points(program_id, accounts, amount, stat)
+        }
+        ScoreInstruction::FreezeMint => process_freeze_mint(program_id, accounts),
+        ScoreInstruction::FreezeScoreAccount => {
+            process_toggle_freeze_score_account(program_id, accounts, true)
+        }
+        ScoreInstruction::ThawScoreAccount => {
+            process_toggle_freeze_score_account(program_id, accounts, false)
+        }
+        ScoreInstruction::ProposeScoreAuthority { new_authority } => {
+            process_propose_score_authority(program_id, accounts, new_authority)
+        }
+        ScoreInstruction::AcceptScoreAuthority => {
+            process_accept_score_authority(program_id, accounts)
+        }
+        ScoreInstruction::RenounceScoreAuthority => {
+            process_renounce_score_authority(program_id, accounts)
+        }
+        ScoreInstruction::UpdateMetadataUri { metadata_uri } => {
+            process_update_metadata_uri(program_id, accounts, metadata_uri)
+        }
+        ScoreInstruction::CloseScoreAccount => process_close_score_account(program_id, accounts),
+        ScoreInstruction::InitializeMultisig { m } => {
+            process_initialize_multisig(program_id, accounts, m)
+        }
+        ScoreInstruction::CreateIssuerDelegate {
+            allowance,
+            expiry_slot,
+        } => process_create_issuer_delegate(program_id, accounts, allowance, expiry_slot),
+        ScoreInstruction::RevokeIssuerDelegate => {
+            process_revoke_issuer_delegate(program_id, accounts)
+        }
+        ScoreInstruction::IssuePointsBatch { amounts } => {
+            process_issue_points_batch(program_id, accounts, amounts)
+        }
+        ScoreInstruction::StartNewSeason => process_start_new_season(program_id, accounts),
+        ScoreInstruction::CreateLeaderboard { capacity } => {
+            process_create_leaderboard(program_id, accounts, capacity)
+        }
+        ScoreInstruction::ClaimSignedPoints {
+            delta,
+            nonce,
+            expiry,
+        } => process_claim_signed_points(program_id, accounts, delta, nonce, expiry),
+        ScoreInstruction::GrantBadge { index } => {
+            process_toggle_badge(program_id, accounts, index, true)
+        }
+        ScoreInstruction::RevokeBadge { index } => {
+            process_toggle_badge(program_id, accounts, index, false)
+        }
+        ScoreInstruction::MigrateScoreAccount => {
+            process_migrate_score_account(program_id, accounts)
+        }
+        ScoreInstruction::MigrateMint => process_migrate_mint(program_id, accounts),
+        ScoreInstruction::AssertMinScore { min } => {
+            process_assert_min_score(program_id, accounts, min)
+        }
+        ScoreInstruction::GetScore => process_get_score(program_id, accounts),
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn process_initialize_score_mint(
     _program_id: &Pubkey,
     accounts: &[AccountInfo],
     score_authority: &Pubkey,
     freeze_authority: Option<Pubkey>,
+    underflow_policy: UnderflowPolicy,
+    allow_self_close: bool,
+    start_timestamp: Option<UnixTimestamp>,
+    end_timestamp: Option<UnixTimestamp>,
+    decay_policy: Option<DecayPolicy>,
+    max_points_per_account: Option<u64>,
+    max_total_supply: Option<u64>,
+    point_representation: PointRepresentation,
+    stat_names: Vec<String>,
     metadata_uri: String,
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
@@ -60,20 +169,2484 @@ fn process_initialize_score_mint(
     let rent = Rent::get()?;
 
     // Check the mint account data - should not yet be initialized.
-    let mut mintdata = try_from_slice_checked::<Mint>(&mint_info.data.borrow(), Mint::SIZE)?;
+    let mut mintdata =
+        try_from_slice_checked::<Mint>(&mint_info.data.borrow(), AccountType::Mint, Mint::SIZE)?;
     if mintdata.state != MintState::Uninitialized {
         return Err(ScoreError::MintExists.into());
     }
     if !rent.is_exempt(mint_info.lamports(), mint_data_len) {
         return Err(ScoreError::ScoringMintNotRentExempt.into());
     }
+    assert_metadata_uri_length(&metadata_uri)?;
+    if let (Some(start_timestamp), Some(end_timestamp)) = (start_timestamp, end_timestamp) {
+        if end_timestamp <= start_timestamp {
+            return Err(ScoreError::InvalidTimeWindow.into());
+        }
+    }
+    if let Some(DecayPolicy::HalfLife {
+        half_life_seconds: 0,
+    }) = decay_policy
+    {
+        return Err(ScoreError::InvalidDecayPolicy.into());
+    }
+    if point_representation == PointRepresentation::Signed
+        && underflow_policy == UnderflowPolicy::ClampToZero
+    {
+        return Err(ScoreError::InvalidUnderflowPolicy.into());
+    }
+    assert_stat_schema(&stat_names)?;
     // Update mint fields. Owner check is implicit: if owner != crate::id(), then writes are rejected.
-    mintdata.score_authority = *score_authority;
+    mintdata.account_type = AccountType::Mint;
+    mintdata.version = Mint::VERSION;
+    mintdata.score_authority = Some(*score_authority);
     mintdata.freeze_authority = freeze_authority;
+    mintdata.underflow_policy = underflow_policy;
+    mintdata.allow_self_close = allow_self_close;
+    mintdata.start_timestamp = start_timestamp;
+    mintdata.end_timestamp = end_timestamp;
+    mintdata.decay_policy = decay_policy;
+    mintdata.max_points_per_account = max_points_per_account;
+    mintdata.max_total_supply = max_total_supply;
+    mintdata.point_representation = point_representation;
+    mintdata.stat_names = stat_names;
     mintdata.state = MintState::Initialized;
     mintdata.metadata_uri = metadata_uri;
 
-    mintdata
-        .serialize(&mut *mint_info.data.borrow_mut())
+    mintdata.serialize(&mut *mint_info.data.borrow_mut())?;
+    ScoreEvent::MintInitialized {
+        mint: *mint_info.key,
+        score_authority: *score_authority,
+        freeze_authority,
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_create_score_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let funder_info = next_account_info(account_info_iter)?;
+    let score_account_info = next_account_info(account_info_iter)?;
+    let wallet_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    let rent = Rent::get()?;
+
+    // Score accounts may only be created for the current season of initialized mints.
+    let mintdata = load_mint(program_id, mint_info)?;
+
+    let (score_account_address, bump_seed) = get_score_account_address_and_bump_seed(
+        wallet_info.key,
+        mint_info.key,
+        mintdata.season,
+        program_id,
+    );
+    if score_account_address != *score_account_info.key {
+        return Err(ScoreError::InvalidScoreAccountAddress.into());
+    }
+
+    let score_account_signer_seeds: &[&[_]] = &[
+        &wallet_info.key.to_bytes(),
+        &mint_info.key.to_bytes(),
+        &mintdata.season.to_le_bytes(),
+        &[bump_seed],
+    ];
+    create_pda_account(
+        funder_info,
+        &rent,
+        ScoreAccount::SIZE,
+        program_id,
+        system_program_info,
+        score_account_info,
+        score_account_signer_seeds,
+    )?;
+
+    let scoredata = ScoreAccount {
+        account_type: AccountType::ScoreAccount,
+        version: ScoreAccount::VERSION,
+        mint: *mint_info.key,
+        wallet: *wallet_info.key,
+        season: mintdata.season,
+        state: ScoreAccountState::Initialized,
+        points: Points::zero(mintdata.point_representation),
+        last_updated: Clock::get()?.unix_timestamp,
+        decay_remainder: 0,
+        badges: 0,
+        stats: [0; Mint::MAX_STATS],
+    };
+    scoredata.serialize(&mut *score_account_info.data.borrow_mut())?;
+    ScoreEvent::ScoreAccountCreated {
+        mint: scoredata.mint,
+        wallet: scoredata.wallet,
+        season: scoredata.season,
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_issue_points(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    stat: Option<u8>,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_info = next_account_info(account_info_iter)?;
+    let score_account_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+
+    let mut mintdata = load_mint(program_id, mint_info)?;
+    assert_mint_active(&mintdata)?;
+    let remaining_infos = validate_issuer(
+        program_id,
+        mint_info.key,
+        &mintdata,
+        authority_info,
+        account_info_iter.as_slice(),
+        amount,
+    )?;
+    if let Some(stat) = stat {
+        return change_stat(
+            program_id,
+            mint_info.key,
+            &mintdata,
+            score_account_info,
+            stat,
+            amount,
+            true,
+        );
+    }
+    record_issuance(&mut mintdata, amount)?;
+    let leaderboard_info = trailing_leaderboard(program_id, remaining_infos);
+    let mut leaderboarddata = leaderboard_info
+        .map(|info| load_leaderboard(program_id, info, mint_info.key, mintdata.season))
+        .transpose()?;
+
+    issue_to_score_account(
+        program_id,
+        mint_info.key,
+        &mintdata,
+        score_account_info,
+        leaderboarddata.as_mut(),
+        amount,
+    )?;
+
+    mintdata.serialize(&mut *mint_info.data.borrow_mut())?;
+    save_leaderboard(leaderboard_info, leaderboarddata)
+}
+
+fn process_issue_points_batch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amounts: Vec<u64>,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let remaining_infos = account_info_iter.as_slice();
+    if remaining_infos.len() < amounts.len() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (signer_infos, score_account_infos) =
+        remaining_infos.split_at(remaining_infos.len() - amounts.len());
+
+    let total_amount = amounts
+        .iter()
+        .try_fold(0u64, |total, amount| total.checked_add(*amount))
+        .ok_or(ScoreError::ScoreOverflow)?;
+
+    let mut mintdata = load_mint(program_id, mint_info)?;
+    assert_mint_active(&mintdata)?;
+    let remaining_infos = validate_issuer(
+        program_id,
+        mint_info.key,
+        &mintdata,
+        authority_info,
+        signer_infos,
+        total_amount,
+    )?;
+    record_issuance(&mut mintdata, total_amount)?;
+    let leaderboard_info = trailing_leaderboard(program_id, remaining_infos);
+    let mut leaderboarddata = leaderboard_info
+        .map(|info| load_leaderboard(program_id, info, mint_info.key, mintdata.season))
+        .transpose()?;
+
+    // Score accounts are only written once every entry has been applied, so a
+    // failing entry leaves all of them unchanged. The same score account may
+    // appear in several entries.
+    let mut scores: Vec<(&AccountInfo, ScoreAccount)> = Vec::new();
+    let mut events = Vec::with_capacity(amounts.len());
+    for (score_account_info, amount) in score_account_infos.iter().zip(amounts) {
+        let index = match scores
+            .iter()
+            .position(|(info, _)| info.key == score_account_info.key)
+        {
+            Some(index) => index,
+            None => {
+                let mut scoredata =
+                    load_score_account(program_id, score_account_info, mint_info.key)?;
+                assert_score_account_active(&mintdata, &scoredata)?;
+                settle_decay(&mintdata, &mut scoredata)?;
+                scores.push((score_account_info, scoredata));
+                scores.len() - 1
+            }
+        };
+        let scoredata = &mut scores[index].1;
+        add_points(&mintdata, scoredata, amount)?;
+        if let Some(leaderboarddata) = leaderboarddata.as_mut() {
+            leaderboarddata.update(&scoredata.wallet, scoredata.points);
+        }
+        events.push(ScoreEvent::PointsIssued {
+            mint: scoredata.mint,
+            wallet: scoredata.wallet,
+            season: scoredata.season,
+            amount,
+            points: scoredata.points,
+        });
+    }
+
+    for (score_account_info, scoredata) in scores {
+        scoredata.serialize(&mut *score_account_info.data.borrow_mut())?;
+    }
+    mintdata.serialize(&mut *mint_info.data.borrow_mut())?;
+    save_leaderboard(leaderboard_info, leaderboarddata)?;
+    for event in events {
+        event.emit();
+    }
+    Ok(())
+}
+
+/// Adds issued points to a score account, recording the new score on the
+/// leaderboard if one is supplied.
+fn issue_to_score_account(
+    program_id: &Pubkey,
+    mint_address: &Pubkey,
+    mintdata: &Mint,
+    score_account_info: &AccountInfo,
+    leaderboard: Option<&mut Leaderboard>,
+    amount: u64,
+) -> ProgramResult {
+    let mut scoredata = load_score_account(program_id, score_account_info, mint_address)?;
+    assert_score_account_active(mintdata, &scoredata)?;
+    settle_decay(mintdata, &mut scoredata)?;
+    add_points(mintdata, &mut scoredata, amount)?;
+    if let Some(leaderboard) = leaderboard {
+        leaderboard.update(&scoredata.wallet, scoredata.points);
+    }
+
+    scoredata.serialize(&mut *score_account_info.data.borrow_mut())?;
+    ScoreEvent::PointsIssued {
+        mint: scoredata.mint,
+        wallet: scoredata.wallet,
+        season: scoredata.season,
+        amount,
+        points: scoredata.points,
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_slash_points(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    stat: Option<u8>,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_info = next_account_info(account_info_iter)?;
+    let score_account_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+
+    let mintdata = load_mint(program_id, mint_info)?;
+    assert_mint_active(&mintdata)?;
+    let signer_infos = account_info_iter.as_slice();
+    validate_authority(
+        program_id,
+        mintdata.score_authority,
+        authority_info,
+        signer_infos,
+    )?;
+    if let Some(stat) = stat {
+        return change_stat(
+            program_id,
+            mint_info.key,
+            &mintdata,
+            score_account_info,
+            stat,
+            amount,
+            false,
+        );
+    }
+    let leaderboard_info = trailing_leaderboard(program_id, signer_infos);
+    let mut leaderboarddata = leaderboard_info
+        .map(|info| load_leaderboard(program_id, info, mint_info.key, mintdata.season))
+        .transpose()?;
+
+    let mut scoredata = load_score_account(program_id, score_account_info, mint_info.key)?;
+    assert_score_account_active(&mintdata, &scoredata)?;
+    settle_decay(&mintdata, &mut scoredata)?;
+    slash_score(&mintdata, &mut scoredata, amount)?;
+    if let Some(leaderboarddata) = leaderboarddata.as_mut() {
+        leaderboarddata.update(&scoredata.wallet, scoredata.points);
+    }
+
+    scoredata.serialize(&mut *score_account_info.data.borrow_mut())?;
+    save_leaderboard(leaderboard_info, leaderboarddata)?;
+    ScoreEvent::PointsSlashed {
+        mint: scoredata.mint,
+        wallet: scoredata.wallet,
+        season: scoredata.season,
+        amount,
+        points: scoredata.points,
+    }
+    .emit();
+    Ok(())
+}
+
+/// Issues points to or slashes points from a stat slot of a score account.
+/// Slashing a stat below zero follows the mint's underflow policy.
+fn change_stat(
+    program_id: &Pubkey,
+    mint_address: &Pubkey,
+    mintdata: &Mint,
+    score_account_info: &AccountInfo,
+    stat: u8,
+    amount: u64,
+    issue: bool,
+) -> ProgramResult {
+    if stat as usize >= mintdata.stat_names.len() {
+        return Err(ScoreError::InvalidStatIndex.into());
+    }
+    let mut scoredata = load_score_account(program_id, score_account_info, mint_address)?;
+    assert_score_account_active(mintdata, &scoredata)?;
+
+    let value = &mut scoredata.stats[stat as usize];
+    *value = if issue {
+        value.checked_add(amount).ok_or(ScoreError::ScoreOverflow)?
+    } else {
+        match value.checked_sub(amount) {
+            Some(value) => value,
+            None => match mintdata.underflow_policy {
+                UnderflowPolicy::Reject => return Err(ScoreError::ScoreUnderflow.into()),
+                UnderflowPolicy::ClampToZero => 0,
+            },
+        }
+    };
+    let value = *value;
+
+    scoredata.serialize(&mut *score_account_info.data.borrow_mut())?;
+    let (mint, wallet, season) = (scoredata.mint, scoredata.wallet, scoredata.season);
+    if issue {
+        ScoreEvent::StatIssued {
+            mint,
+            wallet,
+            season,
+            stat,
+            amount,
+            value,
+        }
+        .emit();
+    } else {
+        ScoreEvent::StatSlashed {
+            mint,
+            wallet,
+            season,
+            stat,
+            amount,
+            value,
+        }
+        .emit();
+    }
+    Ok(())
+}
+
+/// Adds issued points to a score account, up to the mint's maximum points per
+/// account.
+fn add_points(mintdata: &Mint, scoredata: &mut ScoreAccount, amount: u64) -> ProgramResult {
+    scoredata.points = scoredata
+        .points
+        .checked_add(amount)
+        .ok_or(ScoreError::ScoreOverflow)?;
+    match mintdata.max_points_per_account {
+        Some(max_points_per_account)
+            if scoredata.points.as_i128() > max_points_per_account as i128 =>
+        {
+            Err(ScoreError::AccountPointsCapExceeded.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Adds issued points to the mint's total, up to its maximum total supply.
+fn record_issuance(mintdata: &mut Mint, amount: u64) -> ProgramResult {
+    mintdata.total_issued = mintdata
+        .total_issued
+        .checked_add(amount)
+        .ok_or(ScoreError::ScoreOverflow)?;
+    match mintdata.max_total_supply {
+        Some(max_total_supply) if mintdata.total_issued > max_total_supply => {
+            Err(ScoreError::TotalSupplyCapExceeded.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Removes slashed points from a score account according to the mint's
+/// underflow policy.
+fn slash_score(mintdata: &Mint, scoredata: &mut ScoreAccount, amount: u64) -> ProgramResult {
+    scoredata.points = match scoredata.points.checked_sub(amount) {
+        Some(points) => points,
+        None => match mintdata.underflow_policy {
+            UnderflowPolicy::Reject => return Err(ScoreError::ScoreUnderflow.into()),
+            UnderflowPolicy::ClampToZero => Points::zero(mintdata.point_representation),
+        },
+    };
+    Ok(())
+}
+
+fn process_freeze_mint(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+
+    let mut mintdata = load_mint(program_id, mint_info)?;
+    let unix_timestamp = Clock::get()?.unix_timestamp;
+    if mintdata.effective_state(unix_timestamp) == MintState::Frozen {
+        return Err(ScoreError::MintFrozen.into());
+    }
+    validate_authority(
+        program_id,
+        mintdata.freeze_authority,
+        authority_info,
+        account_info_iter.as_slice(),
+    )?;
+
+    mintdata.state = MintState::Frozen;
+    // Decay stops at the end timestamp, preserving the final standings.
+    mintdata.end_timestamp = Some(unix_timestamp);
+
+    mintdata.serialize(&mut *mint_info.data.borrow_mut())?;
+    ScoreEvent::MintFrozen {
+        mint: *mint_info.key,
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_toggle_freeze_score_account(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    freeze: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_info = next_account_info(account_info_iter)?;
+    let score_account_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+
+    let mintdata = load_mint(program_id, mint_info)?;
+    validate_authority(
+        program_id,
+        mintdata.freeze_authority,
+        authority_info,
+        account_info_iter.as_slice(),
+    )?;
+
+    let mut scoredata = load_score_account(program_id, score_account_info, mint_info.key)?;
+    let is_frozen = scoredata.state == ScoreAccountState::Frozen;
+    if freeze == is_frozen {
+        return Err(ScoreError::InvalidState.into());
+    }
+    scoredata.state = if freeze {
+        ScoreAccountState::Frozen
+    } else {
+        ScoreAccountState::Initialized
+    };
+
+    scoredata.serialize(&mut *score_account_info.data.borrow_mut())?;
+    if freeze {
+        ScoreEvent::ScoreAccountFrozen {
+            mint: scoredata.mint,
+            wallet: scoredata.wallet,
+        }
+    } else {
+        ScoreEvent::ScoreAccountThawed {
+            mint: scoredata.mint,
+            wallet: scoredata.wallet,
+        }
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_propose_score_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    new_authority: Option<Pubkey>,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+
+    let mut mintdata = load_mint(program_id, mint_info)?;
+    validate_authority(
+        program_id,
+        mintdata.score_authority,
+        authority_info,
+        account_info_iter.as_slice(),
+    )?;
+
+    mintdata.pending_score_authority = new_authority;
+
+    mintdata.serialize(&mut *mint_info.data.borrow_mut())?;
+    ScoreEvent::AuthorityProposed {
+        mint: *mint_info.key,
+        pending_authority: new_authority,
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_accept_score_authority(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+
+    let mut mintdata = load_mint(program_id, mint_info)?;
+    validate_authority(
+        program_id,
+        mintdata.pending_score_authority,
+        authority_info,
+        account_info_iter.as_slice(),
+    )?;
+
+    let old_authority = mintdata.score_authority;
+    mintdata.score_authority = mintdata.pending_score_authority.take();
+
+    mintdata.serialize(&mut *mint_info.data.borrow_mut())?;
+    ScoreEvent::AuthorityChanged {
+        mint: *mint_info.key,
+        old_authority,
+        new_authority: mintdata.score_authority,
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_renounce_score_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+
+    let mut mintdata = load_mint(program_id, mint_info)?;
+    validate_authority(
+        program_id,
+        mintdata.score_authority,
+        authority_info,
+        account_info_iter.as_slice(),
+    )?;
+
+    let old_authority = mintdata.score_authority.take();
+    mintdata.pending_score_authority = None;
+
+    mintdata.serialize(&mut *mint_info.data.borrow_mut())?;
+    ScoreEvent::AuthorityChanged {
+        mint: *mint_info.key,
+        old_authority,
+        new_authority: None,
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_update_metadata_uri(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    metadata_uri: String,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+
+    let mut mintdata = load_mint(program_id, mint_info)?;
+    validate_authority(
+        program_id,
+        mintdata.score_authority,
+        authority_info,
+        account_info_iter.as_slice(),
+    )?;
+    assert_metadata_uri_length(&metadata_uri)?;
+
+    mintdata.metadata_uri = metadata_uri;
+
+    mintdata.serialize(&mut *mint_info.data.borrow_mut())?;
+    ScoreEvent::MetadataUriUpdated {
+        mint: *mint_info.key,
+        metadata_uri: mintdata.metadata_uri,
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_close_score_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_info = next_account_info(account_info_iter)?;
+    let score_account_info = next_account_info(account_info_iter)?;
+    let destination_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+
+    let signer_infos = account_info_iter.as_slice();
+
+    let mintdata = load_mint(program_id, mint_info)?;
+    let scoredata = load_score_account(program_id, score_account_info, mint_info.key)?;
+    if mintdata.score_authority == Some(*authority_info.key) {
+        validate_authority(
+            program_id,
+            mintdata.score_authority,
+            authority_info,
+            signer_infos,
+        )?;
+    } else if mintdata.allow_self_close {
+        validate_authority(
+            program_id,
+            Some(scoredata.wallet),
+            authority_info,
+            signer_infos,
+        )?;
+        if scoredata.state == ScoreAccountState::Frozen {
+            return Err(ScoreError::AccountFrozen.into());
+        }
+        if scoredata.points.as_i128() < 0 {
+            return Err(ScoreError::NegativeScore.into());
+        }
+    } else {
+        return Err(ScoreError::IncorrectAuthority.into());
+    }
+
+    if score_account_info.key == destination_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    // Standings of frozen mints and closed seasons are final, so a supplied
+    // leaderboard is only updated while the season is current.
+    let standings_final = mintdata.effective_state(Clock::get()?.unix_timestamp)
+        == MintState::Frozen
+        || scoredata.season != mintdata.season;
+    let leaderboard_info =
+        trailing_leaderboard(program_id, signer_infos).filter(|_| !standings_final);
+    let mut leaderboarddata = leaderboard_info
+        .map(|info| load_leaderboard(program_id, info, mint_info.key, scoredata.season))
+        .transpose()?;
+    if let Some(leaderboarddata) = leaderboarddata.as_mut() {
+        leaderboarddata.remove(&scoredata.wallet);
+    }
+
+    let destination_starting_lamports = destination_info.lamports();
+    **destination_info.lamports.borrow_mut() = destination_starting_lamports
+        .checked_add(score_account_info.lamports())
+        .ok_or(ProgramError::InvalidAccountData)?;
+    **score_account_info.lamports.borrow_mut() = 0;
+
+    score_account_info.data.borrow_mut().fill(0);
+    save_leaderboard(leaderboard_info, leaderboarddata)?;
+    ScoreEvent::ScoreAccountClosed {
+        mint: scoredata.mint,
+        wallet: scoredata.wallet,
+        season: scoredata.season,
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_initialize_multisig(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    m: u8,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let multisig_info = next_account_info(account_info_iter)?;
+    let multisig_info_data_len = multisig_info.data_len();
+    let rent = Rent::get()?;
+
+    // Check the multisig account data - should not yet be initialized.
+    let mut multisig = try_from_slice_checked::<Multisig>(
+        &multisig_info.data.borrow(),
+        AccountType::Multisig,
+        Multisig::SIZE,
+    )?;
+    if multisig.is_initialized {
+        return Err(ScoreError::AlreadyInUse.into());
+    }
+    if !rent.is_exempt(multisig_info.lamports(), multisig_info_data_len) {
+        return Err(ScoreError::NotRentExempt.into());
+    }
+
+    let signer_infos = account_info_iter.as_slice();
+    multisig.m = m;
+    multisig.n = signer_infos.len() as u8;
+    if !is_valid_signer_index(signer_infos.len()) {
+        return Err(ScoreError::InvalidNumberOfProvidedSigners.into());
+    }
+    if !is_valid_signer_index(m as usize) || multisig.m > multisig.n {
+        return Err(ScoreError::InvalidNumberOfRequiredSigners.into());
+    }
+    for (i, signer_info) in signer_infos.iter().enumerate() {
+        multisig.signers[i] = *signer_info.key;
+    }
+    // Owner check is implicit: if owner != crate::id(), then writes are rejected.
+    multisig.account_type = AccountType::Multisig;
+    multisig.version = Multisig::VERSION;
+    multisig.is_initialized = true;
+
+    multisig.serialize(&mut *multisig_info.data.borrow_mut())?;
+    ScoreEvent::MultisigInitialized {
+        multisig: *multisig_info.key,
+        m: multisig.m,
+        n: multisig.n,
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_create_issuer_delegate(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    allowance: u64,
+    expiry_slot: Option<u64>,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let funder_info = next_account_info(account_info_iter)?;
+    let issuer_delegate_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let delegate_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let rent = Rent::get()?;
+
+    let mintdata = load_mint(program_id, mint_info)?;
+    validate_authority(
+        program_id,
+        mintdata.score_authority,
+        authority_info,
+        account_info_iter.as_slice(),
+    )?;
+
+    let (issuer_delegate_address, bump_seed) =
+        get_issuer_delegate_address_and_bump_seed(mint_info.key, delegate_info.key, program_id);
+    if issuer_delegate_address != *issuer_delegate_info.key {
+        return Err(ScoreError::InvalidIssuerDelegateAddress.into());
+    }
+
+    let issuer_delegate_signer_seeds: &[&[_]] = &[
+        ISSUER_DELEGATE_SEED,
+        &mint_info.key.to_bytes(),
+        &delegate_info.key.to_bytes(),
+        &[bump_seed],
+    ];
+    create_pda_account(
+        funder_info,
+        &rent,
+        IssuerDelegate::SIZE,
+        program_id,
+        system_program_info,
+        issuer_delegate_info,
+        issuer_delegate_signer_seeds,
+    )?;
+
+    let delegatedata = IssuerDelegate {
+        account_type: AccountType::IssuerDelegate,
+        version: IssuerDelegate::VERSION,
+        mint: *mint_info.key,
+        delegate: *delegate_info.key,
+        is_initialized: true,
+        allowance,
+        expiry_slot,
+    };
+    delegatedata.serialize(&mut *issuer_delegate_info.data.borrow_mut())?;
+    ScoreEvent::IssuerDelegateCreated {
+        mint: delegatedata.mint,
+        delegate: delegatedata.delegate,
+        allowance,
+        expiry_slot,
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_revoke_issuer_delegate(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_info = next_account_info(account_info_iter)?;
+    let issuer_delegate_info = next_account_info(account_info_iter)?;
+    let destination_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+
+    let mintdata = load_mint(program_id, mint_info)?;
+    validate_authority(
+        program_id,
+        mintdata.score_authority,
+        authority_info,
+        account_info_iter.as_slice(),
+    )?;
+    let delegatedata = load_issuer_delegate(program_id, issuer_delegate_info, mint_info.key)?;
+
+    if issuer_delegate_info.key == destination_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let destination_starting_lamports = destination_info.lamports();
+    **destination_info.lamports.borrow_mut() = destination_starting_lamports
+        .checked_add(issuer_delegate_info.lamports())
+        .ok_or(ProgramError::InvalidAccountData)?;
+    **issuer_delegate_info.lamports.borrow_mut() = 0;
+
+    issuer_delegate_info.data.borrow_mut().fill(0);
+    ScoreEvent::IssuerDelegateRevoked {
+        mint: delegatedata.mint,
+        delegate: delegatedata.delegate,
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_start_new_season(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let funder_info = next_account_info(account_info_iter)?;
+    let closed_season_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let rent = Rent::get()?;
+    let unix_timestamp = Clock::get()?.unix_timestamp;
+
+    let mut mintdata = load_mint(program_id, mint_info)?;
+    if mintdata.effective_state(unix_timestamp) == MintState::Frozen {
+        return Err(ScoreError::MintFrozen.into());
+    }
+    validate_authority(
+        program_id,
+        mintdata.score_authority,
+        authority_info,
+        account_info_iter.as_slice(),
+    )?;
+
+    let (closed_season_address, bump_seed) =
+        get_closed_season_address_and_bump_seed(mint_info.key, mintdata.season, program_id);
+    if closed_season_address != *closed_season_info.key {
+        return Err(ScoreError::InvalidClosedSeasonAddress.into());
+    }
+
+    let closed_season_signer_seeds: &[&[_]] = &[
+        CLOSED_SEASON_SEED,
+        &mint_info.key.to_bytes(),
+        &mintdata.season.to_le_bytes(),
+        &[bump_seed],
+    ];
+    create_pda_account(
+        funder_info,
+        &rent,
+        ClosedSeason::SIZE,
+        program_id,
+        system_program_info,
+        closed_season_info,
+        closed_season_signer_seeds,
+    )?;
+
+    let closed_seasondata = ClosedSeason {
+        account_type: AccountType::ClosedSeason,
+        version: ClosedSeason::VERSION,
+        mint: *mint_info.key,
+        season: mintdata.season,
+        is_initialized: true,
+        ended_at: unix_timestamp,
+    };
+    closed_seasondata.serialize(&mut *closed_season_info.data.borrow_mut())?;
+
+    mintdata.season = mintdata
+        .season
+        .checked_add(1)
+        .ok_or(ProgramError::InvalidAccountData)?;
+
+    mintdata.serialize(&mut *mint_info.data.borrow_mut())?;
+    ScoreEvent::SeasonStarted {
+        mint: *mint_info.key,
+        season: mintdata.season,
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_create_leaderboard(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    capacity: u16,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let funder_info = next_account_info(account_info_iter)?;
+    let leaderboard_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let rent = Rent::get()?;
+
+    let mintdata = load_mint(program_id, mint_info)?;
+    validate_authority(
+        program_id,
+        mintdata.score_authority,
+        authority_info,
+        account_info_iter.as_slice(),
+    )?;
+
+    if capacity == 0 || capacity > Leaderboard::MAX_CAPACITY {
+        return Err(ScoreError::InvalidLeaderboardCapacity.into());
+    }
+
+    let (leaderboard_address, bump_seed) =
+        get_leaderboard_address_and_bump_seed(mint_info.key, mintdata.season, program_id);
+    if leaderboard_address != *leaderboard_info.key {
+        return Err(ScoreError::InvalidLeaderboardAddress.into());
+    }
+
+    let leaderboard_signer_seeds: &[&[_]] = &[
+        LEADERBOARD_SEED,
+        &mint_info.key.to_bytes(),
+        &mintdata.season.to_le_bytes(),
+        &[bump_seed],
+    ];
+    create_pda_account(
+        funder_info,
+        &rent,
+        Leaderboard::size(capacity),
+        program_id,
+        system_program_info,
+        leaderboard_info,
+        leaderboard_signer_seeds,
+    )?;
+
+    let leaderboarddata = Leaderboard {
+        account_type: AccountType::Leaderboard,
+        version: Leaderboard::VERSION,
+        mint: *mint_info.key,
+        season: mintdata.season,
+        is_initialized: true,
+        capacity,
+        entries: Vec::new(),
+    };
+    leaderboarddata.serialize(&mut *leaderboard_info.data.borrow_mut())?;
+    ScoreEvent::LeaderboardCreated {
+        mint: leaderboarddata.mint,
+        season: leaderboarddata.season,
+        capacity,
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_claim_signed_points(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    delta: i64,
+    nonce: u64,
+    expiry: UnixTimestamp,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_info = next_account_info(account_info_iter)?;
+    let score_account_info = next_account_info(account_info_iter)?;
+    let claim_nonce_info = next_account_info(account_info_iter)?;
+    let instructions_info = next_account_info(account_info_iter)?;
+    let funder_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let mut mintdata = load_mint(program_id, mint_info)?;
+    assert_mint_active(&mintdata)?;
+    let leaderboard_info = trailing_leaderboard(program_id, account_info_iter.as_slice());
+    let mut leaderboarddata = leaderboard_info
+        .map(|info| load_leaderboard(program_id, info, mint_info.key, mintdata.season))
+        .transpose()?;
+
+    let mut scoredata = load_score_account(program_id, score_account_info, mint_info.key)?;
+    assert_score_account_active(&mintdata, &scoredata)?;
+    if Clock::get()?.unix_timestamp > expiry {
+        return Err(ScoreError::SignedMessageExpired.into());
+    }
+
+    let score_authority = mintdata
+        .score_authority
+        .ok_or(ScoreError::IncorrectAuthority)?;
+    let message = SignedPointsMessage {
+        program_id: *program_id,
+        mint: *mint_info.key,
+        wallet: scoredata.wallet,
+        season: scoredata.season,
+        delta,
+        nonce,
+        expiry,
+    }
+    .try_to_vec()?;
+    assert_ed25519_signature(instructions_info, &score_authority, &message)?;
+
+    let mut noncedata = load_or_create_claim_nonce(
+        program_id,
+        funder_info,
+        claim_nonce_info,
+        system_program_info,
+        mint_info.key,
+        &scoredata.wallet,
+    )?;
+    if nonce <= noncedata.last_nonce {
+        return Err(ScoreError::NonceAlreadyUsed.into());
+    }
+
+    settle_decay(&mintdata, &mut scoredata)?;
+    let amount = delta.unsigned_abs();
+    if delta >= 0 {
+        record_issuance(&mut mintdata, amount)?;
+        add_points(&mintdata, &mut scoredata, amount)?;
+    } else {
+        slash_score(&mintdata, &mut scoredata, amount)?;
+    }
+    noncedata.last_nonce = nonce;
+    if let Some(leaderboarddata) = leaderboarddata.as_mut() {
+        leaderboarddata.update(&scoredata.wallet, scoredata.points);
+    }
+
+    if delta >= 0 {
+        mintdata.serialize(&mut *mint_info.data.borrow_mut())?;
+    }
+    scoredata.serialize(&mut *score_account_info.data.borrow_mut())?;
+    noncedata.serialize(&mut *claim_nonce_info.data.borrow_mut())?;
+    save_leaderboard(leaderboard_info, leaderboarddata)?;
+    if delta >= 0 {
+        ScoreEvent::PointsIssued {
+            mint: scoredata.mint,
+            wallet: scoredata.wallet,
+            season: scoredata.season,
+            amount,
+            points: scoredata.points,
+        }
+    } else {
+        ScoreEvent::PointsSlashed {
+            mint: scoredata.mint,
+            wallet: scoredata.wallet,
+            season: scoredata.season,
+            amount,
+            points: scoredata.points,
+        }
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_toggle_badge(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    index: u8,
+    grant: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_info = next_account_info(account_info_iter)?;
+    let score_account_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+
+    if index >= ScoreAccount::MAX_BADGES {
+        return Err(ScoreError::InvalidBadgeIndex.into());
+    }
+
+    let mintdata = load_mint(program_id, mint_info)?;
+    assert_mint_active(&mintdata)?;
+    validate_authority(
+        program_id,
+        mintdata.score_authority,
+        authority_info,
+        account_info_iter.as_slice(),
+    )?;
+
+    let mut scoredata = load_score_account(program_id, score_account_info, mint_info.key)?;
+    assert_score_account_active(&mintdata, &scoredata)?;
+    if grant == scoredata.has_badge(index) {
+        return Err(ScoreError::InvalidState.into());
+    }
+    scoredata.toggle_badge(index);
+
+    scoredata.serialize(&mut *score_account_info.data.borrow_mut())?;
+    if grant {
+        ScoreEvent::BadgeGranted {
+            mint: scoredata.mint,
+            wallet: scoredata.wallet,
+            index,
+        }
+    } else {
+        ScoreEvent::BadgeRevoked {
+            mint: scoredata.mint,
+            wallet: scoredata.wallet,
+            index,
+        }
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_migrate_score_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let funder_info = next_account_info(account_info_iter)?;
+    let old_score_account_info = next_account_info(account_info_iter)?;
+    let new_score_account_info = next_account_info(account_info_iter)?;
+    let old_wallet_info = next_account_info(account_info_iter)?;
+    let new_wallet_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let rent = Rent::get()?;
+
+    let mintdata = load_mint(program_id, mint_info)?;
+    assert_mint_active(&mintdata)?;
+    let signer_infos = account_info_iter.as_slice();
+    validate_authority(
+        program_id,
+        mintdata.score_authority,
+        authority_info,
+        signer_infos,
+    )?;
+    if !new_wallet_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut scoredata = load_score_account(program_id, old_score_account_info, mint_info.key)?;
+    assert_score_account_active(&mintdata, &scoredata)?;
+    if scoredata.wallet != *old_wallet_info.key {
+        return Err(ScoreError::InvalidScoreAccountAddress.into());
+    }
+    if scoredata.wallet == *new_wallet_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let leaderboard_info = trailing_leaderboard(program_id, signer_infos);
+    let mut leaderboarddata = leaderboard_info
+        .map(|info| load_leaderboard(program_id, info, mint_info.key, scoredata.season))
+        .transpose()?;
+
+    let (score_account_address, bump_seed) = get_score_account_address_and_bump_seed(
+        new_wallet_info.key,
+        mint_info.key,
+        scoredata.season,
+        program_id,
+    );
+    if score_account_address != *new_score_account_info.key {
+        return Err(ScoreError::InvalidScoreAccountAddress.into());
+    }
+    let score_account_signer_seeds: &[&[_]] = &[
+        &new_wallet_info.key.to_bytes(),
+        &mint_info.key.to_bytes(),
+        &scoredata.season.to_le_bytes(),
+        &[bump_seed],
+    ];
+    create_pda_account(
+        funder_info,
+        &rent,
+        ScoreAccount::SIZE,
+        program_id,
+        system_program_info,
+        new_score_account_info,
+        score_account_signer_seeds,
+    )?;
+
+    let old_wallet = scoredata.wallet;
+    scoredata.wallet = *new_wallet_info.key;
+    scoredata.serialize(&mut *new_score_account_info.data.borrow_mut())?;
+    if let Some(leaderboarddata) = leaderboarddata.as_mut() {
+        leaderboarddata.migrate(&old_wallet, &scoredata.wallet);
+    }
+    save_leaderboard(leaderboard_info, leaderboarddata)?;
+
+    let funder_starting_lamports = funder_info.lamports();
+    **funder_info.lamports.borrow_mut() = funder_starting_lamports
+        .checked_add(old_score_account_info.lamports())
+        .ok_or(ProgramError::InvalidAccountData)?;
+    **old_score_account_info.lamports.borrow_mut() = 0;
+    old_score_account_info.data.borrow_mut().fill(0);
+
+    ScoreEvent::ScoreAccountMigrated {
+        mint: scoredata.mint,
+        season: scoredata.season,
+        old_wallet,
+        new_wallet: scoredata.wallet,
+        old_wallet_signed: old_wallet_info.is_signer,
+        points: scoredata.points,
+    }
+    .emit();
+    Ok(())
+}
+
+fn process_migrate_mint(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_info = next_account_info(account_info_iter)?;
+    let funder_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    let rent = Rent::get()?;
+
+    assert_owned_by(mint_info, program_id)?;
+    let from_version = match mint_info.data_len() {
+        MintV0::SIZE => 0,
+        Mint::SIZE => return Err(ScoreError::AccountAlreadyMigrated.into()),
+        _ => return Err(ScoreError::DataTypeMismatch.into()),
+    };
+    let legacy_mintdata: MintV0 = try_from_slice_unchecked(&mint_info.data.borrow())?;
+    // Only initialized mints are migrated.
+    if legacy_mintdata.state == MintState::Uninitialized {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    let mintdata = Mint::from(legacy_mintdata);
+
+    let required_lamports = rent
+        .minimum_balance(Mint::SIZE)
+        .saturating_sub(mint_info.lamports());
+    if required_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(funder_info.key, mint_info.key, required_lamports),
+            &[
+                funder_info.clone(),
+                mint_info.clone(),
+                system_program_info.clone(),
+            ],
+        )?;
+    }
+    mint_info.realloc(Mint::SIZE, true)?;
+    mintdata.serialize(&mut *mint_info.data.borrow_mut())?;
+    ScoreEvent::MintMigrated {
+        mint: *mint_info.key,
+        from_version,
+        to_version: Mint::VERSION,
+    }
+    .emit();
+    Ok(())
+}
+
+/// Loads the wallet's claim nonce account, creating it if it does not exist
+/// yet.
+fn load_or_create_claim_nonce<'a>(
+    program_id: &Pubkey,
+    funder_info: &AccountInfo<'a>,
+    claim_nonce_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    mint_address: &Pubkey,
+    wallet_address: &Pubkey,
+) -> Result<ClaimNonce, ProgramError> {
+    let (claim_nonce_address, bump_seed) =
+        get_claim_nonce_address_and_bump_seed(mint_address, wallet_address, program_id);
+    if claim_nonce_address != *claim_nonce_info.key {
+        return Err(ScoreError::InvalidClaimNonceAddress.into());
+    }
+
+    if claim_nonce_info.data_is_empty() {
+        let claim_nonce_signer_seeds: &[&[_]] = &[
+            CLAIM_NONCE_SEED,
+            &mint_address.to_bytes(),
+            &wallet_address.to_bytes(),
+            &[bump_seed],
+        ];
+        create_pda_account(
+            funder_info,
+            &Rent::get()?,
+            ClaimNonce::SIZE,
+            program_id,
+            system_program_info,
+            claim_nonce_info,
+            claim_nonce_signer_seeds,
+        )?;
+        return Ok(ClaimNonce {
+            account_type: AccountType::ClaimNonce,
+            version: ClaimNonce::VERSION,
+            mint: *mint_address,
+            wallet: *wallet_address,
+            is_initialized: true,
+            last_nonce: 0,
+        });
+    }
+
+    assert_owned_by(claim_nonce_info, program_id)?;
+    let noncedata = try_from_slice_checked::<ClaimNonce>(
+        &claim_nonce_info.data.borrow(),
+        AccountType::ClaimNonce,
+        ClaimNonce::SIZE,
+    )?;
+    if !noncedata.is_initialized {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    Ok(noncedata)
+}
+
+/// Fails unless the instruction preceding the current one is an Ed25519
+/// program instruction verifying a single signature by `signer` over
+/// `message`, with all data contained in that instruction.
+fn assert_ed25519_signature(
+    instructions_info: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> ProgramResult {
+    let current_index = load_current_index_checked(instructions_info)?;
+    let ed25519_index = current_index
+        .checked_sub(1)
+        .ok_or(ScoreError::InvalidSignedMessage)?;
+    let instruction = load_instruction_at_checked(ed25519_index as usize, instructions_info)?;
+    if instruction.program_id != ed25519_program::id() {
+        return Err(ScoreError::InvalidSignedMessage.into());
+    }
+
+    let (signed_public_key, signed_message) =
+        parse_ed25519_instruction(&instruction.data).ok_or(ScoreError::InvalidSignedMessage)?;
+    if signed_public_key != signer.as_ref() || signed_message != message {
+        return Err(ScoreError::InvalidSignedMessage.into());
+    }
+    Ok(())
+}
+
+/// Returns the public key and message of Ed25519 program instruction data
+/// verifying a single signature, or `None` unless the signature, public key
+/// and message are all contained in that instruction's data.
+fn parse_ed25519_instruction(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    // Ed25519 instruction data: signature count, padding, then one set of
+    // offsets: signature offset and instruction index, public key offset and
+    // instruction index, message offset, size and instruction index.
+    const OFFSETS_START: usize = 2;
+    const OFFSETS_SIZE: usize = 14;
+    // Instruction index referring to the Ed25519 instruction itself.
+    const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+    if data.len() < OFFSETS_START + OFFSETS_SIZE || data[0] != 1 {
+        return None;
+    }
+    let read_u16 = |index: usize| {
+        let offset = OFFSETS_START + 2 * index;
+        u16::from_le_bytes([data[offset], data[offset + 1]])
+    };
+    let signature_instruction_index = read_u16(1);
+    let public_key_offset = read_u16(2) as usize;
+    let public_key_instruction_index = read_u16(3);
+    let message_offset = read_u16(4) as usize;
+    let message_size = read_u16(5) as usize;
+    let message_instruction_index = read_u16(6);
+    if signature_instruction_index != CURRENT_INSTRUCTION
+        || public_key_instruction_index != CURRENT_INSTRUCTION
+        || message_instruction_index != CURRENT_INSTRUCTION
+    {
+        return None;
+    }
+
+    let public_key = data.get(public_key_offset..public_key_offset + 32)?;
+    let message = data.get(message_offset..message_offset + message_size)?;
+    Some((public_key, message))
+}
+
+fn process_assert_min_score(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    min: i64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_info = next_account_info(account_info_iter)?;
+    let score_account_info = next_account_info(account_info_iter)?;
+    let wallet_info = next_account_info(account_info_iter)?;
+
+    let mintdata = load_mint(program_id, mint_info)?;
+    let scoredata = load_current_score_account(
+        program_id,
+        mint_info.key,
+        &mintdata,
+        score_account_info,
+        wallet_info.key,
+    )?;
+    // A wallet without a score account holds no points.
+    let points = match scoredata {
+        Some(scoredata) => {
+            scoredata.effective_points(&mintdata, None, Clock::get()?.unix_timestamp)
+        }
+        None => Points::zero(mintdata.point_representation),
+    };
+    if points.as_i128() < min as i128 {
+        msg!("Score {} is below the minimum of {}", points, min);
+        return Err(ScoreError::ScoreBelowMinimum.into());
+    }
+    Ok(())
+}
+
+fn process_get_score(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_info = next_account_info(account_info_iter)?;
+    let score_account_info = next_account_info(account_info_iter)?;
+
+    let mintdata = load_mint(program_id, mint_info)?;
+    let scoredata = load_score_account(program_id, score_account_info, mint_info.key)?;
+    let season_ended_at = if scoredata.season == mintdata.season {
+        None
+    } else {
+        let closed_season_info = next_account_info(account_info_iter)?;
+        let closed_seasondata = load_closed_season(
+            program_id,
+            closed_season_info,
+            mint_info.key,
+            scoredata.season,
+        )?;
+        Some(closed_seasondata.ended_at)
+    };
+    let unix_timestamp = Clock::get()?.unix_timestamp;
+    let return_data = ScoreReturnData {
+        mint: scoredata.mint,
+        wallet: scoredata.wallet,
+        season: scoredata.season,
+        points: scoredata.effective_points(&mintdata, season_ended_at, unix_timestamp),
+        account_frozen: scoredata.state == ScoreAccountState::Frozen,
+        mint_frozen: mintdata.effective_state(unix_timestamp) == MintState::Frozen,
+    };
+    set_return_data(&return_data.try_to_vec()?);
+    Ok(())
+}
+
+/// Loads the wallet's score account for the mint's current season, or `None`
+/// if no score account exists at its address.
+fn load_current_score_account(
+    program_id: &Pubkey,
+    mint_address: &Pubkey,
+    mintdata: &Mint,
+    score_account_info: &AccountInfo,
+    wallet_address: &Pubkey,
+) -> Result<Option<ScoreAccount>, ProgramError> {
+    let (score_account_address, _) = get_score_account_address_and_bump_seed(
+        wallet_address,
+        mint_address,
+        mintdata.season,
+        program_id,
+    );
+    if score_account_address != *score_account_info.key {
+        return Err(ScoreError::InvalidScoreAccountAddress.into());
+    }
+    if score_account_info.data_is_empty() {
+        return Ok(None);
+    }
+    load_score_account(program_id, score_account_info, mint_address).map(Some)
+}
+
+/// Loads an initialized mint owned by this program.
+fn load_mint(program_id: &Pubkey, mint_info: &AccountInfo) -> Result<Mint, ProgramError> {
+    assert_owned_by(mint_info, program_id)?;
+    let mintdata =
+        try_from_slice_checked::<Mint>(&mint_info.data.borrow(), AccountType::Mint, Mint::SIZE)?;
+    if mintdata.state == MintState::Uninitialized {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    Ok(mintdata)
+}
+
+/// Loads an initialized score account owned by this program for the given mint.
+fn load_score_account(
+    program_id: &Pubkey,
+    score_account_info: &AccountInfo,
+    mint_address: &Pubkey,
+) -> Result<ScoreAccount, ProgramError> {
+    assert_owned_by(score_account_info, program_id)?;
+    let scoredata = try_from_slice_checked::<ScoreAccount>(
+        &score_account_info.data.borrow(),
+        AccountType::ScoreAccount,
+        ScoreAccount::SIZE,
+    )?;
+    if scoredata.state == ScoreAccountState::Uninitialized {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    if scoredata.mint != *mint_address {
+        return Err(ScoreError::MintMismatch.into());
+    }
+    Ok(scoredata)
+}
+
+/// Loads an initialized issuer delegate owned by this program for the given mint.
+fn load_issuer_delegate(
+    program_id: &Pubkey,
+    issuer_delegate_info: &AccountInfo,
+    mint_address: &Pubkey,
+) -> Result<IssuerDelegate, ProgramError> {
+    assert_owned_by(issuer_delegate_info, program_id)?;
+    let delegatedata = try_from_slice_checked::<IssuerDelegate>(
+        &issuer_delegate_info.data.borrow(),
+        AccountType::IssuerDelegate,
+        IssuerDelegate::SIZE,
+    )?;
+    if !delegatedata.is_initialized {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    if delegatedata.mint != *mint_address {
+        return Err(ScoreError::MintMismatch.into());
+    }
+    Ok(delegatedata)
+}
+
+/// Loads the record of when the given season of the mint ended.
+fn load_closed_season(
+    program_id: &Pubkey,
+    closed_season_info: &AccountInfo,
+    mint_address: &Pubkey,
+    season: u32,
+) -> Result<ClosedSeason, ProgramError> {
+    let (closed_season_address, _) =
+        get_closed_season_address_and_bump_seed(mint_address, season, program_id);
+    if closed_season_address != *closed_season_info.key {
+        return Err(ScoreError::InvalidClosedSeasonAddress.into());
+    }
+    assert_owned_by(closed_season_info, program_id)?;
+    let closed_seasondata = try_from_slice_checked::<ClosedSeason>(
+        &closed_season_info.data.borrow(),
+        AccountType::ClosedSeason,
+        ClosedSeason::SIZE,
+    )?;
+    if !closed_seasondata.is_initialized {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    Ok(closed_seasondata)
+}
+
+/// Loads the leaderboard for the given season of the mint.
+fn load_leaderboard(
+    program_id: &Pubkey,
+    leaderboard_info: &AccountInfo,
+    mint_address: &Pubkey,
+    season: u32,
+) -> Result<Leaderboard, ProgramError> {
+    assert_owned_by(leaderboard_info, program_id)?;
+    let data = leaderboard_info.data.borrow();
+    if data.first() != Some(&(AccountType::Leaderboard as u8)) {
+        return Err(ScoreError::DataTypeMismatch.into());
+    }
+    assert_account_version(&data)?;
+    let leaderboarddata: Leaderboard = try_from_slice_unchecked(&data)?;
+    if data.len() != Leaderboard::size(leaderboarddata.capacity) {
+        return Err(ScoreError::DataTypeMismatch.into());
+    }
+    if !leaderboarddata.is_initialized {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    if leaderboarddata.mint != *mint_address {
+        return Err(ScoreError::MintMismatch.into());
+    }
+    if leaderboarddata.season != season {
+        return Err(ScoreError::LeaderboardSeasonMismatch.into());
+    }
+    Ok(leaderboarddata)
+}
+
+/// Returns the optional leaderboard supplied after the accounts authorizing a
+/// score change, recognized by its owner and account discriminator.
+fn trailing_leaderboard<'a, 'b>(
+    program_id: &Pubkey,
+    account_infos: &'a [AccountInfo<'b>],
+) -> Option<&'a AccountInfo<'b>> {
+    account_infos.last().filter(|info| {
+        info.owner == program_id
+            && info.data.borrow().first() == Some(&(AccountType::Leaderboard as u8))
+    })
+}
+
+/// Saves the leaderboard, if one was supplied.
+fn save_leaderboard(
+    leaderboard_info: Option<&AccountInfo>,
+    leaderboarddata: Option<Leaderboard>,
+) -> ProgramResult {
+    match (leaderboard_info, leaderboarddata) {
+        (Some(leaderboard_info), Some(leaderboarddata)) => leaderboarddata
+            .serialize(&mut *leaderboard_info.data.borrow_mut())
+            .map_err(|e| e.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Fails unless the authority may issue the given number of points, either as
+/// the mint's score authority or as an issuer delegate with enough allowance.
+/// For a delegate, the first of the signer accounts is its issuer delegate
+/// account. Returns the accounts following the issuer delegate account, or all
+/// signer accounts for the score authority.
+fn validate_issuer<'a, 'b>(
+    program_id: &Pubkey,
+    mint_address: &Pubkey,
+    mintdata: &Mint,
+    authority_info: &AccountInfo,
+    signer_infos: &'a [AccountInfo<'b>],
+    amount: u64,
+) -> Result<&'a [AccountInfo<'b>], ProgramError> {
+    if mintdata.score_authority.is_some() && mintdata.score_authority != Some(*authority_info.key) {
+        let (issuer_delegate_info, remaining_infos) = signer_infos
+            .split_first()
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        consume_issuer_allowance(
+            program_id,
+            mint_address,
+            authority_info,
+            issuer_delegate_info,
+            amount,
+        )?;
+        Ok(remaining_infos)
+    } else {
+        validate_authority(
+            program_id,
+            mintdata.score_authority,
+            authority_info,
+            signer_infos,
+        )?;
+        Ok(signer_infos)
+    }
+}
+
+/// Deducts issued points from the allowance of a signing issuer delegate.
+fn consume_issuer_allowance(
+    program_id: &Pubkey,
+    mint_address: &Pubkey,
+    delegate_info: &AccountInfo,
+    issuer_delegate_info: &AccountInfo,
+    amount: u64,
+) -> ProgramResult {
+    let mut delegatedata = load_issuer_delegate(program_id, issuer_delegate_info, mint_address)?;
+    if delegatedata.delegate != *delegate_info.key {
+        return Err(ScoreError::IncorrectAuthority.into());
+    }
+    if !delegate_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if let Some(expiry_slot) = delegatedata.expiry_slot {
+        if Clock::get()?.slot > expiry_slot {
+            return Err(ScoreError::DelegateExpired.into());
+        }
+    }
+    delegatedata.allowance = delegatedata
+        .allowance
+        .checked_sub(amount)
+        .ok_or(ScoreError::InsufficientAllowance)?;
+
+    delegatedata
+        .serialize(&mut *issuer_delegate_info.data.borrow_mut())
         .map_err(|e| e.into())
 }
+
+/// Fails unless points on the mint may currently change, according to its
+/// state and time window.
+fn assert_mint_active(mintdata: &Mint) -> ProgramResult {
+    let unix_timestamp = Clock::get()?.unix_timestamp;
+    if mintdata.effective_state(unix_timestamp) == MintState::Frozen {
+        return Err(ScoreError::MintFrozen.into());
+    }
+    if !mintdata.has_started(unix_timestamp) {
+        return Err(ScoreError::MintNotStarted.into());
+    }
+    Ok(())
+}
+
+/// Applies the decay accrued since the score account was last updated, moving
+/// its last updated time to now.
+fn settle_decay(mintdata: &Mint, scoredata: &mut ScoreAccount) -> ProgramResult {
+    scoredata.settle_decay(mintdata, Clock::get()?.unix_timestamp);
+    Ok(())
+}
+
+/// Fails unless points on the score account may currently change. Score
+/// accounts of closed seasons may no longer change.
+fn assert_score_account_active(mintdata: &Mint, scoredata: &ScoreAccount) -> ProgramResult {
+    if scoredata.state == ScoreAccountState::Frozen {
+        return Err(ScoreError::AccountFrozen.into());
+    }
+    if scoredata.season != mintdata.season {
+        return Err(ScoreError::SeasonClosed.into());
+    }
+    Ok(())
+}
+
+/// Fails unless the metadata URI fits within the mint account.
+fn assert_metadata_uri_length(metadata_uri: &str) -> ProgramResult {
+    if metadata_uri.len() > Mint::MAX_METADATA_URI_LENGTH {
+        return Err(ScoreError::MetadataUriTooLong.into());
+    }
+    Ok(())
+}
+
+/// Fails unless there are at most `Mint::MAX_STATS` stat names, each unique,
+/// non-empty and no longer than `Mint::MAX_STAT_NAME_LENGTH` bytes.
+fn assert_stat_schema(stat_names: &[String]) -> ProgramResult {
+    if stat_names.len() > Mint::MAX_STATS
+        || stat_names
+            .iter()
+            .any(|name| name.is_empty() || name.len() > Mint::MAX_STAT_NAME_LENGTH)
+    {
+        return Err(ScoreError::InvalidStatSchema.into());
+    }
+    for (i, name) in stat_names.iter().enumerate() {
+        if stat_names[..i].contains(name) {
+            return Err(ScoreError::InvalidStatSchema.into());
+        }
+    }
+    Ok(())
+}
+
+/// Fails unless the authority account matches the expected authority and
+/// signed. An unset authority never matches. When the authority is a
+/// `Multisig`, at least M of its N signers must be among the signer accounts.
+fn validate_authority(
+    program_id: &Pubkey,
+    expected_authority: Option<Pubkey>,
+    authority_info: &AccountInfo,
+    signer_infos: &[AccountInfo],
+) -> ProgramResult {
+    if expected_authority != Some(*authority_info.key) {
+        return Err(ScoreError::IncorrectAuthority.into());
+    }
+    if authority_info.owner == program_id && authority_info.data_len() == Multisig::SIZE {
+        let multisig = try_from_slice_checked::<Multisig>(
+            &authority_info.data.borrow(),
+            AccountType::Multisig,
+            Multisig::SIZE,
+        )?;
+        if !multisig.is_initialized {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        let mut num_signers = 0;
+        let mut matched = [false; MAX_SIGNERS];
+        for signer_info in signer_infos.iter() {
+            for (position, key) in multisig.signers[0..multisig.n as usize].iter().enumerate() {
+                if key == signer_info.key && !matched[position] {
+                    if !signer_info.is_signer {
+                        return Err(ProgramError::MissingRequiredSignature);
+                    }
+                    matched[position] = true;
+                    num_signers += 1;
+                }
+            }
+        }
+        if num_signers < multisig.m {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        return Ok(());
+    } else if !authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUBLIC_KEY_OFFSET: u16 = 16;
+    const SIGNATURE_OFFSET: u16 = PUBLIC_KEY_OFFSET + 32;
+    const MESSAGE_OFFSET: u16 = SIGNATURE_OFFSET + 64;
+
+    /// Builds Ed25519 program instruction data laid out like
+    /// `new_ed25519_instruction`, with the given offsets.
+    fn ed25519_data(public_key: &Pubkey, message: &[u8], offsets: [u16; 7]) -> Vec<u8> {
+        let mut data = vec![1, 0];
+        for offset in offsets.iter() {
+            data.extend_from_slice(&offset.to_le_bytes());
+        }
+        data.extend_from_slice(public_key.as_ref());
+        data.extend_from_slice(&[7; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    fn offsets(message: &[u8]) -> [u16; 7] {
+        [
+            SIGNATURE_OFFSET,
+            u16::MAX,
+            PUBLIC_KEY_OFFSET,
+            u16::MAX,
+            MESSAGE_OFFSET,
+            message.len() as u16,
+            u16::MAX,
+        ]
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction() {
+        let public_key = Pubkey::new_unique();
+        let message = b"signed points message";
+        let data = ed25519_data(&public_key, message, offsets(message));
+        assert_eq!(
+            parse_ed25519_instruction(&data),
+            Some((public_key.as_ref(), &message[..]))
+        );
+
+        let data = ed25519_data(&public_key, b"", offsets(b""));
+        assert_eq!(
+            parse_ed25519_instruction(&data),
+            Some((public_key.as_ref(), &b""[..]))
+        );
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction_rejects_signature_count() {
+        let public_key = Pubkey::new_unique();
+        let message = b"message";
+        let mut data = ed25519_data(&public_key, message, offsets(message));
+        for &count in &[0, 2] {
+            data[0] = count;
+            assert_eq!(parse_ed25519_instruction(&data), None);
+        }
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction_rejects_other_instructions() {
+        let public_key = Pubkey::new_unique();
+        let message = b"message";
+        // Each of the signature, public key and message must be read from the
+        // Ed25519 instruction itself, not from another instruction.
+        for &index in &[1, 3, 6] {
+            let mut offsets = offsets(message);
+            offsets[index] = 0;
+            let data = ed25519_data(&public_key, message, offsets);
+            assert_eq!(parse_ed25519_instruction(&data), None);
+        }
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction_rejects_out_of_bounds() {
+        let public_key = Pubkey::new_unique();
+        let message = b"message";
+        let data = ed25519_data(&public_key, message, offsets(message));
+        assert_eq!(parse_ed25519_instruction(&data[..15]), None);
+        assert_eq!(parse_ed25519_instruction(&[]), None);
+        assert_eq!(parse_ed25519_instruction(&data[..data.len() - 1]), None);
+
+        let mut message_too_long = offsets(message);
+        message_too_long[5] = u16::MAX;
+        let data = ed25519_data(&public_key, message, message_too_long);
+        assert_eq!(parse_ed25519_instruction(&data), None);
+
+        let mut public_key_past_end = offsets(message);
+        public_key_past_end[2] = (data.len() - 31) as u16;
+        let data = ed25519_data(&public_key, message, public_key_past_end);
+        assert_eq!(parse_ed25519_instruction(&data), None);
+    }
+
+    #[test]
+    fn test_stat_schema() {
+        let names =
+            |names: &[&str]| -> Vec<String> { names.iter().map(|name| name.to_string()).collect() };
+        assert_eq!(assert_stat_schema(&[]), Ok(()));
+        assert_eq!(assert_stat_schema(&names(&["kills", "wins"])), Ok(()));
+        let max_length_name = "x".repeat(Mint::MAX_STAT_NAME_LENGTH);
+        assert_eq!(
+            assert_stat_schema(std::slice::from_ref(&max_length_name)),
+            Ok(())
+        );
+
+        let invalid = Err(ScoreError::InvalidStatSchema.into());
+        assert_eq!(
+            assert_stat_schema(&names(&["kills", "wins", "kills"])),
+            invalid
+        );
+        assert_eq!(assert_stat_schema(&names(&["wins", "wins"])), invalid);
+        assert_eq!(assert_stat_schema(&names(&["kills", ""])), invalid);
+        assert_eq!(assert_stat_schema(&[max_length_name + "x"]), invalid);
+        let too_many: Vec<String> = (0..=Mint::MAX_STATS).map(|i| i.to_string()).collect();
+        assert_eq!(assert_stat_schema(&too_many), invalid);
+        assert_eq!(assert_stat_schema(&too_many[1..]), Ok(()));
+    }
+
+    /// Account state borrowed by an `AccountInfo`.
+    struct TestAccount {
+        key: Pubkey,
+        owner: Pubkey,
+        is_signer: bool,
+        lamports: u64,
+        data: Vec<u8>,
+    }
+
+    impl TestAccount {
+        fn new(owner: Pubkey, is_signer: bool, data: Vec<u8>) -> Self {
+            Self {
+                key: Pubkey::new_unique(),
+                owner,
+                is_signer,
+                lamports: 1,
+                data,
+            }
+        }
+
+        fn wallet(is_signer: bool) -> Self {
+            Self::new(Pubkey::default(), is_signer, Vec::new())
+        }
+
+        fn info(&mut self) -> AccountInfo<'_> {
+            AccountInfo::new(
+                &self.key,
+                self.is_signer,
+                true,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                false,
+                0,
+            )
+        }
+    }
+
+    fn infos(accounts: &mut [TestAccount]) -> Vec<AccountInfo<'_>> {
+        accounts.iter_mut().map(TestAccount::info).collect()
+    }
+
+    /// Builds an M-of-N multisig account over the keys of the given wallets.
+    fn test_multisig(program_id: &Pubkey, m: u8, wallets: &[TestAccount]) -> TestAccount {
+        let mut signers = [Pubkey::default(); MAX_SIGNERS];
+        for (signer, wallet) in signers.iter_mut().zip(wallets) {
+            *signer = wallet.key;
+        }
+        let multisig = Multisig {
+            account_type: AccountType::Multisig,
+            version: Multisig::VERSION,
+            m,
+            n: wallets.len() as u8,
+            is_initialized: true,
+            signers,
+        };
+        TestAccount::new(*program_id, false, multisig.try_to_vec().unwrap())
+    }
+
+    #[test]
+    fn test_validate_authority_single_signer() {
+        let program_id = crate::id();
+        let mut authority = TestAccount::wallet(true);
+        let key = authority.key;
+        let authority_info = authority.info();
+        assert_eq!(
+            validate_authority(&program_id, Some(key), &authority_info, &[]),
+            Ok(())
+        );
+        assert_eq!(
+            validate_authority(
+                &program_id,
+                Some(Pubkey::new_unique()),
+                &authority_info,
+                &[]
+            ),
+            Err(ScoreError::IncorrectAuthority.into())
+        );
+        assert_eq!(
+            validate_authority(&program_id, None, &authority_info, &[]),
+            Err(ScoreError::IncorrectAuthority.into())
+        );
+
+        let mut unsigned_authority = TestAccount::wallet(false);
+        let key = unsigned_authority.key;
+        assert_eq!(
+            validate_authority(&program_id, Some(key), &unsigned_authority.info(), &[]),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+    }
+
+    #[test]
+    fn test_validate_authority_m_of_n() {
+        let program_id = crate::id();
+        let mut wallets: Vec<TestAccount> = (0..3).map(|_| TestAccount::wallet(true)).collect();
+        let mut multisig = test_multisig(&program_id, 2, &wallets);
+        let key = multisig.key;
+        let multisig_info = multisig.info();
+        let validate = |signer_infos: &[AccountInfo]| {
+            validate_authority(&program_id, Some(key), &multisig_info, signer_infos)
+        };
+
+        let signer_infos = infos(&mut wallets);
+        assert_eq!(validate(&signer_infos), Ok(()));
+        assert_eq!(
+            validate(&[signer_infos[2].clone(), signer_infos[0].clone()]),
+            Ok(())
+        );
+
+        // Too few signers.
+        assert_eq!(
+            validate(&signer_infos[1..2]),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+        assert_eq!(validate(&[]), Err(ProgramError::MissingRequiredSignature));
+
+        // A signer supplied twice only counts once.
+        assert_eq!(
+            validate(&[signer_infos[1].clone(), signer_infos[1].clone()]),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+
+        // Signers outside the multisig do not count.
+        let mut outsider = TestAccount::wallet(true);
+        assert_eq!(
+            validate(&[signer_infos[0].clone(), outsider.info()]),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+    }
+
+    #[test]
+    fn test_validate_authority_multisig_signer_did_not_sign() {
+        let program_id = crate::id();
+        let mut wallets = vec![
+            TestAccount::wallet(true),
+            TestAccount::wallet(false),
+            TestAccount::wallet(true),
+        ];
+        let mut multisig = test_multisig(&program_id, 2, &wallets);
+        let key = multisig.key;
+        let multisig_info = multisig.info();
+        let signer_infos = infos(&mut wallets);
+
+        // Listing a multisig signer which did not sign fails, even when enough
+        // other signers did.
+        assert_eq!(
+            validate_authority(&program_id, Some(key), &multisig_info, &signer_infos),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+        assert_eq!(
+            validate_authority(
+                &program_id,
+                Some(key),
+                &multisig_info,
+                &[signer_infos[0].clone(), signer_infos[2].clone()]
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_validate_authority_uninitialized_multisig() {
+        let program_id = crate::id();
+        let mut wallets: Vec<TestAccount> = (0..2).map(|_| TestAccount::wallet(true)).collect();
+        let mut multisig = TestAccount::new(program_id, false, vec![0; Multisig::SIZE]);
+        let key = multisig.key;
+        assert_eq!(
+            validate_authority(
+                &program_id,
+                Some(key),
+                &multisig.info(),
+                &infos(&mut wallets)
+            ),
+            Err(ProgramError::UninitializedAccount)
+        );
+    }
+
+    fn test_mint(score_authority: Pubkey) -> Mint {
+        Mint::from(MintV0 {
+            score_authority,
+            freeze_authority: None,
+            state: MintState::Initialized,
+            metadata_uri: String::new(),
+        })
+    }
+
+    fn test_issuer_delegate(
+        program_id: &Pubkey,
+        mint: &Pubkey,
+        delegate: &Pubkey,
+        allowance: u64,
+        expiry_slot: Option<u64>,
+    ) -> TestAccount {
+        let delegatedata = IssuerDelegate {
+            account_type: AccountType::IssuerDelegate,
+            version: IssuerDelegate::VERSION,
+            mint: *mint,
+            delegate: *delegate,
+            is_initialized: true,
+            allowance,
+            expiry_slot,
+        };
+        let mut data = delegatedata.try_to_vec().unwrap();
+        data.resize(IssuerDelegate::SIZE, 0);
+        TestAccount::new(*program_id, false, data)
+    }
+
+    fn allowance(issuer_delegate: &TestAccount) -> u64 {
+        try_from_slice_unchecked::<IssuerDelegate>(&issuer_delegate.data)
+            .unwrap()
+            .allowance
+    }
+
+    const TEST_SLOT: u64 = 100;
+    const TEST_UNIX_TIMESTAMP: UnixTimestamp = 1_000_000;
+
+    /// Serves `Clock::get` at `TEST_SLOT` and `TEST_UNIX_TIMESTAMP`.
+    struct ClockStub;
+
+    impl solana_program::program_stubs::SyscallStubs for ClockStub {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let clock = Clock {
+                slot: TEST_SLOT,
+                unix_timestamp: TEST_UNIX_TIMESTAMP,
+                ..Clock::default()
+            };
+            unsafe { *(var_addr as *mut Clock) = clock };
+            solana_program::entrypoint::SUCCESS
+        }
+    }
+
+    /// Installs the test clock. Tests reading the clock must call this first,
+    /// as they may run before any other test installed it.
+    fn set_test_clock() {
+        solana_program::program_stubs::set_syscall_stubs(Box::new(ClockStub));
+    }
+
+    #[test]
+    fn test_validate_issuer_allowance() {
+        let program_id = crate::id();
+        let mint = Pubkey::new_unique();
+        let mut delegate = TestAccount::wallet(true);
+        let mut issuer_delegate =
+            test_issuer_delegate(&program_id, &mint, &delegate.key, 100, None);
+        let mut leaderboard = TestAccount::wallet(false);
+        let mintdata = test_mint(Pubkey::new_unique());
+        let delegate_info = delegate.info();
+        let mut validate = |issuer_delegate: &mut TestAccount, amount| {
+            validate_issuer(
+                &program_id,
+                &mint,
+                &mintdata,
+                &delegate_info,
+                &[issuer_delegate.info(), leaderboard.info()],
+                amount,
+            )
+            .map(|remaining_infos| remaining_infos.len())
+        };
+
+        // The issuer delegate account is consumed, leaving the leaderboard.
+        assert_eq!(validate(&mut issuer_delegate, 60), Ok(1));
+        assert_eq!(allowance(&issuer_delegate), 40);
+        assert_eq!(
+            validate(&mut issuer_delegate, 41),
+            Err(ScoreError::InsufficientAllowance.into())
+        );
+        assert_eq!(allowance(&issuer_delegate), 40);
+        assert_eq!(validate(&mut issuer_delegate, 40), Ok(1));
+        assert_eq!(allowance(&issuer_delegate), 0);
+        assert_eq!(
+            validate(&mut issuer_delegate, 1),
+            Err(ScoreError::InsufficientAllowance.into())
+        );
+        assert_eq!(validate(&mut issuer_delegate, 0), Ok(1));
+    }
+
+    #[test]
+    fn test_validate_issuer_expiry_slot() {
+        set_test_clock();
+        let program_id = crate::id();
+        let mint = Pubkey::new_unique();
+        let mintdata = test_mint(Pubkey::new_unique());
+        let mut delegate = TestAccount::wallet(true);
+        let delegate_info = delegate.info();
+
+        // The delegate may still issue points in its expiry slot.
+        for (expiry_slot, expected) in [
+            (TEST_SLOT + 1, Ok(())),
+            (TEST_SLOT, Ok(())),
+            (TEST_SLOT - 1, Err(ScoreError::DelegateExpired.into())),
+            (0, Err(ScoreError::DelegateExpired.into())),
+        ] {
+            let mut issuer_delegate =
+                test_issuer_delegate(&program_id, &mint, delegate_info.key, 10, Some(expiry_slot));
+            let expected_allowance = if expected.is_ok() { 0 } else { 10 };
+            assert_eq!(
+                validate_issuer(
+                    &program_id,
+                    &mint,
+                    &mintdata,
+                    &delegate_info,
+                    &[issuer_delegate.info()],
+                    10,
+                )
+                .map(|_| ()),
+                expected
+            );
+            assert_eq!(allowance(&issuer_delegate), expected_allowance);
+        }
+    }
+
+    #[test]
+    fn test_validate_issuer_rejects_invalid_delegate_accounts() {
+        let program_id = crate::id();
+        let mint = Pubkey::new_unique();
+        let mintdata = test_mint(Pubkey::new_unique());
+        let mut delegate = TestAccount::wallet(true);
+        let delegate_key = delegate.key;
+        let delegate_info = delegate.info();
+        let validate = |issuer_delegate: &mut TestAccount| {
+            validate_issuer(
+                &program_id,
+                &mint,
+                &mintdata,
+                &delegate_info,
+                &[issuer_delegate.info()],
+                1,
+            )
+            .map(|_| ())
+        };
+
+        // A revoked issuer delegate account is zeroed.
+        let mut revoked = TestAccount::new(program_id, false, vec![0; IssuerDelegate::SIZE]);
+        assert_eq!(
+            validate(&mut revoked),
+            Err(ProgramError::UninitializedAccount)
+        );
+
+        // Issuer delegate accounts of other delegates or mints.
+        let mut other_delegate =
+            test_issuer_delegate(&program_id, &mint, &Pubkey::new_unique(), 10, None);
+        assert_eq!(
+            validate(&mut other_delegate),
+            Err(ScoreError::IncorrectAuthority.into())
+        );
+        let mut other_mint =
+            test_issuer_delegate(&program_id, &Pubkey::new_unique(), &delegate_key, 10, None);
+        assert_eq!(
+            validate(&mut other_mint),
+            Err(ScoreError::MintMismatch.into())
+        );
+
+        // An issuer delegate account not owned by the program.
+        let mut foreign = test_issuer_delegate(&program_id, &mint, &delegate_key, 10, None);
+        foreign.owner = Pubkey::new_unique();
+        assert_eq!(
+            validate(&mut foreign),
+            Err(ScoreError::IncorrectOwner.into())
+        );
+
+        // A delegate which did not sign.
+        let mut unsigned_delegate = TestAccount::wallet(false);
+        let mut issuer_delegate =
+            test_issuer_delegate(&program_id, &mint, &unsigned_delegate.key, 10, None);
+        assert_eq!(
+            validate_issuer(
+                &program_id,
+                &mint,
+                &mintdata,
+                &unsigned_delegate.info(),
+                &[issuer_delegate.info()],
+                1,
+            )
+            .map(|_| ()),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+
+        // No issuer delegate account.
+        assert_eq!(
+            validate_issuer(&program_id, &mint, &mintdata, &delegate_info, &[], 1).map(|_| ()),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+    }
+
+    fn test_mint_account(program_id: &Pubkey, mintdata: &Mint) -> TestAccount {
+        let mut data = mintdata.try_to_vec().unwrap();
+        data.resize(Mint::SIZE, 0);
+        TestAccount::new(*program_id, false, data)
+    }
+
+    fn test_score_account(program_id: &Pubkey, mint: &Pubkey, points: Points) -> TestAccount {
+        let scoredata = ScoreAccount {
+            account_type: AccountType::ScoreAccount,
+            version: ScoreAccount::VERSION,
+            mint: *mint,
+            wallet: Pubkey::new_unique(),
+            season: 0,
+            state: ScoreAccountState::Initialized,
+            points,
+            last_updated: TEST_UNIX_TIMESTAMP,
+            decay_remainder: 0,
+            badges: 0,
+            stats: [0; Mint::MAX_STATS],
+        };
+        TestAccount::new(*program_id, false, scoredata.try_to_vec().unwrap())
+    }
+
+    fn load_test_mint(mint: &TestAccount) -> Mint {
+        try_from_slice_unchecked(&mint.data).unwrap()
+    }
+
+    fn points(score_account: &TestAccount) -> Points {
+        try_from_slice_unchecked::<ScoreAccount>(&score_account.data)
+            .unwrap()
+            .points
+    }
+
+    fn process(
+        program_id: &Pubkey,
+        accounts: &mut [TestAccount],
+        instruction: ScoreInstruction,
+    ) -> ProgramResult {
+        process_instruction(
+            program_id,
+            &infos(accounts),
+            &instruction.try_to_vec().unwrap(),
+        )
+    }
+
+    fn issue_points(amount: u64) -> ScoreInstruction {
+        ScoreInstruction::IssuePoints { amount, stat: None }
+    }
+
+    fn slash_points(amount: u64) -> ScoreInstruction {
+        ScoreInstruction::SlashPoints { amount, stat: None }
+    }
+
+    #[test]
+    fn test_issue_points_total_supply_cap() {
+        set_test_clock();
+        let program_id = crate::id();
+        let authority = TestAccount::wallet(true);
+        let mut mintdata = test_mint(authority.key);
+        mintdata.max_total_supply = Some(100);
+        mintdata.total_issued = 90;
+        let mint = test_mint_account(&program_id, &mintdata);
+        let score_account = test_score_account(&program_id, &mint.key, Points::Unsigned(0));
+        let mut accounts = [mint, score_account, authority];
+
+        assert_eq!(
+            process(&program_id, &mut accounts, issue_points(11)),
+            Err(ScoreError::TotalSupplyCapExceeded.into())
+        );
+        assert_eq!(load_test_mint(&accounts[0]).total_issued, 90);
+        assert_eq!(points(&accounts[1]), Points::Unsigned(0));
+
+        assert_eq!(
+            process(&program_id, &mut accounts, issue_points(10)),
+            Ok(())
+        );
+        assert_eq!(load_test_mint(&accounts[0]).total_issued, 100);
+        assert_eq!(points(&accounts[1]), Points::Unsigned(10));
+
+        // Slashing does not free up supply.
+        assert_eq!(
+            process(&program_id, &mut accounts, slash_points(10)),
+            Ok(())
+        );
+        assert_eq!(
+            process(&program_id, &mut accounts, issue_points(1)),
+            Err(ScoreError::TotalSupplyCapExceeded.into())
+        );
+    }
+
+    #[test]
+    fn test_issue_points_per_account_cap() {
+        set_test_clock();
+        let program_id = crate::id();
+        let authority = TestAccount::wallet(true);
+        let mut mintdata = test_mint(authority.key);
+        mintdata.max_points_per_account = Some(50);
+        mintdata.max_total_supply = Some(1_000);
+        let mint = test_mint_account(&program_id, &mintdata);
+        let score_account = test_score_account(&program_id, &mint.key, Points::Unsigned(45));
+        let mut accounts = [mint, score_account, authority];
+
+        assert_eq!(
+            process(&program_id, &mut accounts, issue_points(6)),
+            Err(ScoreError::AccountPointsCapExceeded.into())
+        );
+        assert_eq!(points(&accounts[1]), Points::Unsigned(45));
+        assert_eq!(process(&program_id, &mut accounts, issue_points(5)), Ok(()));
+        assert_eq!(points(&accounts[1]), Points::Unsigned(50));
+        assert_eq!(load_test_mint(&accounts[0]).total_issued, 5);
+
+        // The cap applies to each score account, not to the mint.
+        let other_score_account =
+            test_score_account(&program_id, &accounts[0].key, Points::Unsigned(0));
+        let [mint, _, authority] = accounts;
+        let mut accounts = [mint, other_score_account, authority];
+        assert_eq!(
+            process(&program_id, &mut accounts, issue_points(50)),
+            Ok(())
+        );
+        assert_eq!(load_test_mint(&accounts[0]).total_issued, 55);
+    }
+
+    fn test_slash_accounts(
+        point_representation: PointRepresentation,
+        underflow_policy: UnderflowPolicy,
+        points: Points,
+    ) -> (Pubkey, [TestAccount; 3]) {
+        let program_id = crate::id();
+        let authority = TestAccount::wallet(true);
+        let mut mintdata = test_mint(authority.key);
+        mintdata.point_representation = point_representation;
+        mintdata.underflow_policy = underflow_policy;
+        let mint = test_mint_account(&program_id, &mintdata);
+        let score_account = test_score_account(&program_id, &mint.key, points);
+        (program_id, [mint, score_account, authority])
+    }
+
+    #[test]
+    fn test_slash_points_reject_underflow() {
+        set_test_clock();
+        let (program_id, mut accounts) = test_slash_accounts(
+            PointRepresentation::Unsigned,
+            UnderflowPolicy::Reject,
+            Points::Unsigned(5),
+        );
+
+        assert_eq!(
+            process(&program_id, &mut accounts, slash_points(6)),
+            Err(ScoreError::ScoreUnderflow.into())
+        );
+        assert_eq!(points(&accounts[1]), Points::Unsigned(5));
+        assert_eq!(process(&program_id, &mut accounts, slash_points(5)), Ok(()));
+        assert_eq!(points(&accounts[1]), Points::Unsigned(0));
+    }
+
+    #[test]
+    fn test_slash_points_clamp_to_zero() {
+        set_test_clock();
+        let (program_id, mut accounts) = test_slash_accounts(
+            PointRepresentation::Unsigned,
+            UnderflowPolicy::ClampToZero,
+            Points::Unsigned(5),
+        );
+
+        assert_eq!(process(&program_id, &mut accounts, slash_points(6)), Ok(()));
+        assert_eq!(points(&accounts[1]), Points::Unsigned(0));
+        assert_eq!(process(&program_id, &mut accounts, slash_points(1)), Ok(()));
+        assert_eq!(points(&accounts[1]), Points::Unsigned(0));
+    }
+
+    #[test]
+    fn test_slash_points_signed() {
+        set_test_clock();
+        let (program_id, mut accounts) = test_slash_accounts(
+            PointRepresentation::Signed,
+            UnderflowPolicy::Reject,
+            Points::Signed(5),
+        );
+
+        // Signed scores go negative rather than underflowing.
+        assert_eq!(process(&program_id, &mut accounts, slash_points(8)), Ok(()));
+        assert_eq!(points(&accounts[1]), Points::Signed(-3));
+        assert_eq!(
+            process(&program_id, &mut accounts, slash_points(u64::MAX)),
+            Err(ScoreError::ScoreUnderflow.into())
+        );
+        assert_eq!(points(&accounts[1]), Points::Signed(-3));
+    }
+
+    #[test]
+    fn test_issue_points_batch_all_or_nothing() {
+        set_test_clock();
+        let program_id = crate::id();
+        let authority = TestAccount::wallet(true);
+        let mut mintdata = test_mint(authority.key);
+        mintdata.max_points_per_account = Some(50);
+        let mint = test_mint_account(&program_id, &mintdata);
+        let first = test_score_account(&program_id, &mint.key, Points::Unsigned(0));
+        let second = test_score_account(&program_id, &mint.key, Points::Unsigned(45));
+        let third = test_score_account(&program_id, &mint.key, Points::Unsigned(0));
+        let mut accounts = [mint, authority, first, second, third];
+        let batch = |amounts: Vec<u64>| ScoreInstruction::IssuePointsBatch { amounts };
+
+        // The second entry exceeds its cap, so the first is not applied either.
+        assert_eq!(
+            process(&program_id, &mut accounts, batch(vec![10, 10, 10])),
+            Err(ScoreError::AccountPointsCapExceeded.into())
+        );
+        assert_eq!(load_test_mint(&accounts[0]).total_issued, 0);
+        assert_eq!(points(&accounts[2]), Points::Unsigned(0));
+        assert_eq!(points(&accounts[3]), Points::Unsigned(45));
+        assert_eq!(points(&accounts[4]), Points::Unsigned(0));
+
+        assert_eq!(
+            process(&program_id, &mut accounts, batch(vec![10, 5, 10])),
+            Ok(())
+        );
+        assert_eq!(load_test_mint(&accounts[0]).total_issued, 25);
+        assert_eq!(points(&accounts[2]), Points::Unsigned(10));
+        assert_eq!(points(&accounts[3]), Points::Unsigned(50));
+        assert_eq!(points(&accounts[4]), Points::Unsigned(10));
+    }
+}
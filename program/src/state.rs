@@ -6,35 +6,175 @@ use {
         BorshSerialize,
     },
     solana_program::{
+        clock::UnixTimestamp,
         // program_option::COption,
         // program_pack::IsInitialized,
         pubkey::Pubkey,
     },
+    std::{cmp::Ordering, collections::BTreeMap, convert::TryFrom, fmt},
 };
 
+/// Size of the discriminator and layout version leading every account.
+pub const ACCOUNT_HEADER_SIZE: usize = 1 + 1;
+
+/// Discriminator leading the data of every account owned by the program, so
+/// that an account of one type is never read as another.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum AccountType {
+    /// Account data is not yet initialized
+    Uninitialized,
+    /// A scoring `Mint`.
+    Mint,
+    /// A `ScoreAccount`.
+    ScoreAccount,
+    /// A `Multisig`.
+    Multisig,
+    /// An `IssuerDelegate`.
+    IssuerDelegate,
+    /// A `Leaderboard`.
+    Leaderboard,
+    /// A `ClosedSeason`.
+    ClosedSeason,
+    /// A `ClaimNonce`.
+    ClaimNonce,
+}
+
+impl AccountType {
+    /// Returns the current layout version of accounts of this type. Data of
+    /// uninitialized accounts has no layout version and reads as version 0.
+    pub fn current_version(&self) -> u8 {
+        match self {
+            AccountType::Uninitialized => 0,
+            AccountType::Mint => Mint::VERSION,
+            AccountType::ScoreAccount => ScoreAccount::VERSION,
+            AccountType::Multisig => Multisig::VERSION,
+            AccountType::IssuerDelegate => IssuerDelegate::VERSION,
+            AccountType::Leaderboard => Leaderboard::VERSION,
+            AccountType::ClosedSeason => ClosedSeason::VERSION,
+            AccountType::ClaimNonce => ClaimNonce::VERSION,
+        }
+    }
+}
+
 /// Scoring Mint data, supporting on-chain programs issuing points and client
 /// applications that render wallet scores.
 // #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
 pub struct Mint {
-    /// Authority used to issue or slash points. The mint authority may only be
-    /// set during mint creation.
-    pub score_authority: Pubkey,
+    /// Account discriminator, `AccountType::Mint` once initialized.
+    pub account_type: AccountType,
+    /// Layout version of the account data, `Mint::VERSION` once initialized
+    /// or migrated.
+    pub version: u8,
+    /// Authority used to issue or slash points. The score authority may be
+    /// transferred in two steps, by proposing a new authority which must then
+    /// accept, or permanently renounced.
+    pub score_authority: Option<Pubkey>,
+    /// Score authority proposed by the current score authority, pending its
+    /// acceptance.
+    pub pending_score_authority: Option<Pubkey>,
     /// Optional authority to freeze all scores globally. Used for time-limited
     /// games or events. Freezing the mint supports creating final leaderboards.
     /// May not be modified after creating the mint.
     pub freeze_authority: Option<Pubkey>,
     /// Lifecycle state for the mint.
     pub state: MintState,
+    /// Behavior when slashing would take a score below zero. May not be
+    /// modified after creating the mint.
+    pub underflow_policy: UnderflowPolicy,
+    /// Whether wallets may close their own score accounts to reclaim rent. The
+    /// score authority may always close score accounts. May not be modified
+    /// after creating the mint.
+    pub allow_self_close: bool,
+    /// Optional unix timestamp before which points may not be issued or
+    /// slashed.
+    pub start_timestamp: Option<UnixTimestamp>,
+    /// Optional unix timestamp from which the mint behaves as frozen, without
+    /// requiring a `FreezeMint` instruction. Set to the freeze time when the
+    /// mint is frozen.
+    pub end_timestamp: Option<UnixTimestamp>,
+    /// Optional policy by which scores fade while a wallet is inactive. Decay
+    /// is applied whenever a score account changes, and stops at the mint's
+    /// end timestamp. May not be modified after creating the mint.
+    pub decay_policy: Option<DecayPolicy>,
+    /// The current season, starting at zero. Points may only change on score
+    /// accounts of the current season; earlier seasons remain readable.
+    pub season: u32,
+    /// Optional maximum number of points a score account may hold. May not be
+    /// modified after creating the mint.
+    pub max_points_per_account: Option<u64>,
+    /// Optional maximum number of points which may ever be issued by the mint,
+    /// across all seasons. May not be modified after creating the mint.
+    pub max_total_supply: Option<u64>,
+    /// Total number of points issued by the mint. Slashing and decay do not
+    /// reduce the total.
+    pub total_issued: u64,
+    /// Whether score accounts hold unsigned or signed points. May not be
+    /// modified after creating the mint.
+    pub point_representation: PointRepresentation,
+    /// Names of the stat slots recorded by each score account alongside its
+    /// points, such as kills, wins or playtime. At most `Mint::MAX_STATS`
+    /// unique names of at most `Mint::MAX_STAT_NAME_LENGTH` bytes. May not be
+    /// modified after creating the mint.
+    pub stat_names: Vec<String>,
     /// URI for JSON metadata describing this mint's points. Maximum length is
-    /// 128 bytes. Expected format is the metaplex format:
+    /// `Mint::MAX_METADATA_URI_LENGTH` bytes. Expected format is the metaplex format:
     /// https://docs.metaplex.com/nft-standard#uri-json-schema
     pub metadata_uri: String,
 }
 
 impl Mint {
+    /// Current layout version of a Scoring mint account. Version 0 mints
+    /// predate the account header and are upgraded with `MigrateMint`.
+    pub const VERSION: u8 = 1;
+
+    /// Maximum length in bytes of the metadata URI.
+    pub const MAX_METADATA_URI_LENGTH: usize = 128;
+
+    /// Size of the fixed-length fields of a Scoring mint account.
+    const FIXED_FIELDS_SIZE: usize = 33 + 33 + 33 + 1 + 1 + 1 + 9 + 9 + 10 + 4 + 9 + 9 + 8 + 1;
+
+    /// Maximum number of stat slots.
+    pub const MAX_STATS: usize = 8;
+
+    /// Maximum length in bytes of a stat name.
+    pub const MAX_STAT_NAME_LENGTH: usize = 16;
+
     /// Maximum size of the data in a Scoring mint account.
-    pub const SIZE : usize = 32 + 33 + 1 + 128;
+    pub const SIZE: usize = ACCOUNT_HEADER_SIZE
+        + Self::FIXED_FIELDS_SIZE
+        + 4
+        + Self::MAX_STATS * (4 + Self::MAX_STAT_NAME_LENGTH)
+        + 4
+        + Self::MAX_METADATA_URI_LENGTH;
+
+    /// Returns the mint's state at the given unix timestamp. A mint with an
+    /// end timestamp is frozen once that time is reached.
+    pub fn effective_state(&self, unix_timestamp: UnixTimestamp) -> MintState {
+        match self.end_timestamp {
+            Some(end_timestamp)
+                if self.state == MintState::Initialized && unix_timestamp >= end_timestamp =>
+            {
+                MintState::Frozen
+            }
+            _ => self.state,
+        }
+    }
+
+    /// Returns the number of points which may still be issued, or `None` if
+    /// the total supply is unlimited.
+    pub fn remaining_supply(&self) -> Option<u64> {
+        self.max_total_supply
+            .map(|max_total_supply| max_total_supply.saturating_sub(self.total_issued))
+    }
+
+    /// Returns whether the mint's start timestamp, if any, has been reached.
+    pub fn has_started(&self, unix_timestamp: UnixTimestamp) -> bool {
+        match self.start_timestamp {
+            Some(start_timestamp) => unix_timestamp >= start_timestamp,
+            None => true,
+        }
+    }
 }
 
 // impl Sealed for Mint {}
@@ -44,6 +184,56 @@ impl Mint {
 //     }
 // }
 
+/// Version 0 Scoring mint data, laid out before the account header and the
+/// current mint options were introduced. Version 0 mints are upgraded to the
+/// current layout with `MigrateMint`.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct MintV0 {
+    /// Authority used to issue or slash points.
+    pub score_authority: Pubkey,
+    /// Optional authority to freeze all scores globally.
+    pub freeze_authority: Option<Pubkey>,
+    /// Lifecycle state for the mint.
+    pub state: MintState,
+    /// URI for JSON metadata describing this mint's points.
+    pub metadata_uri: String,
+}
+
+impl MintV0 {
+    /// Size of the data in a version 0 Scoring mint account.
+    pub const SIZE: usize = 32 + 33 + 1 + 128;
+}
+
+impl From<MintV0> for Mint {
+    /// Maps a version 0 mint onto the current layout. Options introduced
+    /// since take their defaults: no pending score authority, time window,
+    /// decay, caps or stats, unsigned points which may not underflow, no
+    /// self-closing, and the first season. Points issued before the
+    /// migration are not counted towards the total issued.
+    fn from(mint: MintV0) -> Self {
+        Mint {
+            account_type: AccountType::Mint,
+            version: Mint::VERSION,
+            score_authority: Some(mint.score_authority),
+            pending_score_authority: None,
+            freeze_authority: mint.freeze_authority,
+            state: mint.state,
+            underflow_policy: UnderflowPolicy::Reject,
+            allow_self_close: false,
+            start_timestamp: None,
+            end_timestamp: None,
+            decay_policy: None,
+            season: 0,
+            max_points_per_account: None,
+            max_total_supply: None,
+            total_issued: 0,
+            point_representation: PointRepresentation::Unsigned,
+            stat_names: Vec::new(),
+            metadata_uri: mint.metadata_uri,
+        }
+    }
+}
+
 /// Mint state.
 #[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq)]
 pub enum MintState {
@@ -55,3 +245,928 @@ pub enum MintState {
     /// issued in the future.
     Frozen,
 }
+
+/// Number of seconds in a day, the period of linear decay.
+pub const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Policy by which scores fade while a wallet is inactive.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum DecayPolicy {
+    /// Points halve every `half_life_seconds`. Within a half-life, points
+    /// decrease linearly towards the next halving. Decay restarts from the
+    /// current points whenever they change.
+    HalfLife {
+        /// Number of seconds for a score to halve. Must be non-zero.
+        half_life_seconds: u64,
+    },
+    /// Points decrease by a fixed amount per day, down to zero.
+    Linear {
+        /// Number of points lost per day.
+        points_per_day: u64,
+    },
+}
+
+impl DecayPolicy {
+    /// Returns the points remaining after decaying for the given number of
+    /// seconds, along with the new decay remainder, see
+    /// `ScoreAccount::decay_remainder`. Only integer arithmetic is used, so
+    /// on-chain and off-chain results are identical.
+    pub fn decay(&self, points: u64, decay_remainder: u64, elapsed_seconds: u64) -> (u64, u64) {
+        if points == 0 {
+            return (0, 0);
+        }
+        match *self {
+            DecayPolicy::HalfLife { half_life_seconds } => {
+                if half_life_seconds == 0 {
+                    return (points, 0);
+                }
+                let half_lives = elapsed_seconds / half_life_seconds;
+                if half_lives >= 64 {
+                    return (0, 0);
+                }
+                let points = points >> half_lives;
+                let remainder = elapsed_seconds % half_life_seconds;
+                let lost = (points / 2) as u128 * remainder as u128 / half_life_seconds as u128;
+                (points - lost as u64, 0)
+            }
+            DecayPolicy::Linear { points_per_day } => {
+                let decayed =
+                    points_per_day as u128 * elapsed_seconds as u128 + decay_remainder as u128;
+                let lost = decayed / SECONDS_PER_DAY as u128;
+                if lost >= points as u128 {
+                    return (0, 0);
+                }
+                (
+                    points - lost as u64,
+                    (decayed % SECONDS_PER_DAY as u128) as u64,
+                )
+            }
+        }
+    }
+}
+
+/// Behavior when slashing more points than a score account holds.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum UnderflowPolicy {
+    /// The slash is rejected with `ScoreError::ScoreUnderflow`.
+    Reject,
+    /// The score is clamped to zero. Only supported by unsigned mints.
+    ClampToZero,
+}
+
+/// Numeric representation of the points held by a mint's score accounts.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum PointRepresentation {
+    /// Scores are non-negative, as `u64`.
+    Unsigned,
+    /// Scores may be negative, as `i64`, such as ratings or penalty scores.
+    Signed,
+}
+
+/// Points held by a score account, in its mint's representation. Points of
+/// both representations compare by their numeric value.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Points {
+    /// Points of an unsigned mint.
+    Unsigned(u64),
+    /// Points of a signed mint.
+    Signed(i64),
+}
+
+impl Points {
+    /// Returns zero points in the given representation.
+    pub fn zero(representation: PointRepresentation) -> Self {
+        match representation {
+            PointRepresentation::Unsigned => Points::Unsigned(0),
+            PointRepresentation::Signed => Points::Signed(0),
+        }
+    }
+
+    /// Returns the numeric value of the points, exact for both
+    /// representations.
+    pub fn as_i128(self) -> i128 {
+        match self {
+            Points::Unsigned(points) => points as i128,
+            Points::Signed(points) => points as i128,
+        }
+    }
+
+    /// Adds points, returning `None` on overflow.
+    pub fn checked_add(self, amount: u64) -> Option<Self> {
+        match self {
+            Points::Unsigned(points) => points.checked_add(amount).map(Points::Unsigned),
+            Points::Signed(points) => i64::try_from(points as i128 + amount as i128)
+                .ok()
+                .map(Points::Signed),
+        }
+    }
+
+    /// Subtracts points, returning `None` on underflow. Signed points may
+    /// become negative.
+    pub fn checked_sub(self, amount: u64) -> Option<Self> {
+        match self {
+            Points::Unsigned(points) => points.checked_sub(amount).map(Points::Unsigned),
+            Points::Signed(points) => i64::try_from(points as i128 - amount as i128)
+                .ok()
+                .map(Points::Signed),
+        }
+    }
+
+    /// Returns the points after decaying towards zero for the given number of
+    /// seconds, along with the new decay remainder, see `DecayPolicy::decay`.
+    pub fn decay(
+        self,
+        decay_policy: &DecayPolicy,
+        decay_remainder: u64,
+        elapsed_seconds: u64,
+    ) -> (Self, u64) {
+        match self {
+            Points::Unsigned(points) => {
+                let (points, decay_remainder) =
+                    decay_policy.decay(points, decay_remainder, elapsed_seconds);
+                (Points::Unsigned(points), decay_remainder)
+            }
+            Points::Signed(points) => {
+                let (magnitude, decay_remainder) =
+                    decay_policy.decay(points.unsigned_abs(), decay_remainder, elapsed_seconds);
+                let magnitude = magnitude as i128;
+                let points = if points < 0 { -magnitude } else { magnitude };
+                (Points::Signed(points as i64), decay_remainder)
+            }
+        }
+    }
+}
+
+impl PartialOrd for Points {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Points {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_i128().cmp(&other.as_i128())
+    }
+}
+
+impl fmt::Display for Points {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Points::Unsigned(points) => points.fmt(f),
+            Points::Signed(points) => points.fmt(f),
+        }
+    }
+}
+
+/// Minimum number of multisignature signers (min N)
+pub const MIN_SIGNERS: usize = 1;
+/// Maximum number of multisignature signers (max N)
+pub const MAX_SIGNERS: usize = 11;
+
+/// Multisignature data, usable as the score or freeze authority of a `Mint`.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct Multisig {
+    /// Account discriminator, `AccountType::Multisig` once initialized.
+    pub account_type: AccountType,
+    /// Layout version of the account data.
+    pub version: u8,
+    /// Number of signers required
+    pub m: u8,
+    /// Number of valid signers
+    pub n: u8,
+    /// Is `true` if this structure has been initialized
+    pub is_initialized: bool,
+    /// Signer public keys
+    pub signers: [Pubkey; MAX_SIGNERS],
+}
+
+impl Multisig {
+    /// Current layout version of a multisignature account.
+    pub const VERSION: u8 = 1;
+
+    /// Size of the data in a multisignature account.
+    pub const SIZE: usize = ACCOUNT_HEADER_SIZE + 1 + 1 + 1 + 32 * MAX_SIGNERS;
+}
+
+/// Checks that the number of signers is within the multisignature bounds.
+pub fn is_valid_signer_index(index: usize) -> bool {
+    (MIN_SIGNERS..=MAX_SIGNERS).contains(&index)
+}
+
+/// Score account data, recording the points held by a single wallet for a
+/// season of a scoring mint. The account address is derived from the wallet
+/// and mint addresses and the season, see `get_score_account_address`.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct ScoreAccount {
+    /// Account discriminator, always `AccountType::ScoreAccount`.
+    pub account_type: AccountType,
+    /// Layout version of the account data.
+    pub version: u8,
+    /// The scoring mint this account records points for.
+    pub mint: Pubkey,
+    /// The wallet whose score is recorded by this account.
+    pub wallet: Pubkey,
+    /// The mint season this account records points for.
+    pub season: u32,
+    /// Lifecycle state for the score account.
+    pub state: ScoreAccountState,
+    /// The points held by the wallet as of `last_updated`, before any decay
+    /// since then, in the mint's representation.
+    pub points: Points,
+    /// Unix timestamp at which the points were last updated.
+    pub last_updated: UnixTimestamp,
+    /// Fraction of a point already decayed as of `last_updated` but not yet
+    /// deducted from the points, in units of `1 / SECONDS_PER_DAY` points.
+    /// Carrying it over keeps linear decay independent of how often the
+    /// points change. Always zero for half-life decay.
+    pub decay_remainder: u64,
+    /// Bitset of the badges granted to the wallet, with bit `i` recording
+    /// the badge at index `i`.
+    pub badges: u64,
+    /// Values of the mint's stat slots, indexed like `Mint::stat_names`.
+    pub stats: [u64; Mint::MAX_STATS],
+}
+
+impl ScoreAccount {
+    /// Current layout version of a score account.
+    pub const VERSION: u8 = 1;
+
+    /// Size of the data in a score account.
+    pub const SIZE: usize =
+        ACCOUNT_HEADER_SIZE + 32 + 32 + 4 + 1 + 9 + 8 + 8 + 8 + 8 * Mint::MAX_STATS;
+
+    /// Number of badges a score account may record.
+    pub const MAX_BADGES: u8 = 64;
+
+    /// Returns whether the badge at the given index has been granted.
+    pub fn has_badge(&self, index: u8) -> bool {
+        index < Self::MAX_BADGES && self.badges & (1 << index) != 0
+    }
+
+    /// Grants the badge at the given index if it has not been granted, and
+    /// revokes it otherwise. The index must be below `MAX_BADGES`.
+    pub fn toggle_badge(&mut self, index: u8) {
+        self.badges ^= 1 << index;
+    }
+
+    /// Returns the value of each of the mint's stat slots, by name.
+    pub fn stats<'a>(&self, mint: &'a Mint) -> BTreeMap<&'a str, u64> {
+        mint.stat_names
+            .iter()
+            .map(String::as_str)
+            .zip(self.stats.iter().copied())
+            .collect()
+    }
+
+    /// Returns the names of the granted badges described by the mint's
+    /// metadata, in index order. Granted badges without a descriptor are
+    /// skipped.
+    pub fn badge_names<'a>(&self, descriptors: &'a [BadgeDescriptor]) -> Vec<&'a str> {
+        let mut descriptors: Vec<&BadgeDescriptor> = descriptors
+            .iter()
+            .filter(|descriptor| self.has_badge(descriptor.index))
+            .collect();
+        descriptors.sort_by_key(|descriptor| descriptor.index);
+        descriptors
+            .into_iter()
+            .map(|descriptor| descriptor.name.as_str())
+            .collect()
+    }
+
+    /// Returns the points held by the wallet at the given unix timestamp,
+    /// after applying the mint's decay policy. `season_ended_at` is the time
+    /// the account's season ended, as recorded by its `ClosedSeason` account,
+    /// or `None` while the season is current. Points of a closed season decay
+    /// up to the time it ended and are final from then on.
+    pub fn effective_points(
+        &self,
+        mint: &Mint,
+        season_ended_at: Option<UnixTimestamp>,
+        unix_timestamp: UnixTimestamp,
+    ) -> Points {
+        let unix_timestamp = match season_ended_at {
+            Some(season_ended_at) => unix_timestamp.min(season_ended_at),
+            None => unix_timestamp,
+        };
+        self.decay(mint, unix_timestamp).0
+    }
+
+    /// Applies the decay accrued up to the given unix timestamp to the
+    /// points of an account of the mint's current season and moves
+    /// `last_updated` to that time, so that points changed afterwards only
+    /// decay from then on. The effective points are unchanged.
+    pub fn settle_decay(&mut self, mint: &Mint, unix_timestamp: UnixTimestamp) {
+        let (points, decay_remainder) = self.decay(mint, unix_timestamp);
+        self.points = points;
+        self.decay_remainder = decay_remainder;
+        self.last_updated = unix_timestamp;
+    }
+
+    /// Returns the points and the decay remainder at the given unix
+    /// timestamp. Decay stops at the mint's end timestamp.
+    fn decay(&self, mint: &Mint, unix_timestamp: UnixTimestamp) -> (Points, u64) {
+        let unix_timestamp = match mint.end_timestamp {
+            Some(end_timestamp) => unix_timestamp.min(end_timestamp),
+            None => unix_timestamp,
+        };
+        match mint.decay_policy {
+            Some(decay_policy) => {
+                let elapsed_seconds = unix_timestamp.saturating_sub(self.last_updated).max(0);
+                self.points
+                    .decay(&decay_policy, self.decay_remainder, elapsed_seconds as u64)
+            }
+            None => (self.points, self.decay_remainder),
+        }
+    }
+}
+
+/// Description of a badge, parsed from the `badges` array of the mint's
+/// metadata JSON, for example:
+///
+/// ```json
+/// "badges": [
+///   { "index": 0, "name": "First win" },
+///   { "index": 1, "name": "100 matches" }
+/// ]
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct BadgeDescriptor {
+    /// Index of the badge's bit in a score account's badge bitset.
+    pub index: u8,
+    /// Display name of the badge.
+    pub name: String,
+}
+
+/// Score account state.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum ScoreAccountState {
+    /// Score account is not yet initialized
+    Uninitialized,
+    /// Score account is initialized and may receive points.
+    Initialized,
+    /// Score account has been frozen by the mint freeze authority and its
+    /// points may not change until it is thawed.
+    Frozen,
+}
+
+/// Issuer delegate data, allowing a delegate to issue a limited number of
+/// points on a scoring mint without holding the score authority. The account
+/// address is derived from the mint and delegate addresses, see
+/// `get_issuer_delegate_address`.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct IssuerDelegate {
+    /// Account discriminator, always `AccountType::IssuerDelegate`.
+    pub account_type: AccountType,
+    /// Layout version of the account data.
+    pub version: u8,
+    /// The scoring mint the delegate may issue points on.
+    pub mint: Pubkey,
+    /// The delegate allowed to issue points.
+    pub delegate: Pubkey,
+    /// Is `true` if this structure has been initialized
+    pub is_initialized: bool,
+    /// The number of points the delegate may still issue.
+    pub allowance: u64,
+    /// Optional slot after which the delegate may no longer issue points.
+    pub expiry_slot: Option<u64>,
+}
+
+impl IssuerDelegate {
+    /// Current layout version of an issuer delegate account.
+    pub const VERSION: u8 = 1;
+
+    /// Size of the data in an issuer delegate account.
+    pub const SIZE: usize = ACCOUNT_HEADER_SIZE + 32 + 32 + 1 + 8 + 9;
+}
+
+/// Closed season data, recording when a season of a scoring mint ended so that
+/// the points of its score accounts decay up to that time. The account is
+/// created by `StartNewSeason` and its address is derived from the mint address
+/// and the closed season, see `get_closed_season_address`.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct ClosedSeason {
+    /// Account discriminator, always `AccountType::ClosedSeason`.
+    pub account_type: AccountType,
+    /// Layout version of the account data.
+    pub version: u8,
+    /// The scoring mint the season belongs to.
+    pub mint: Pubkey,
+    /// The closed season.
+    pub season: u32,
+    /// Is `true` if this structure has been initialized
+    pub is_initialized: bool,
+    /// Unix timestamp at which the season ended.
+    pub ended_at: UnixTimestamp,
+}
+
+impl ClosedSeason {
+    /// Current layout version of a closed season account.
+    pub const VERSION: u8 = 1;
+
+    /// Size of the data in a closed season account.
+    pub const SIZE: usize = ACCOUNT_HEADER_SIZE + 32 + 4 + 1 + 8;
+}
+
+/// Leaderboard data, recording the highest scores of a season of a scoring
+/// mint. The account address is derived from the mint address and the season,
+/// see `get_leaderboard_address`.
+///
+/// The leaderboard is updated whenever points are issued to or slashed from a
+/// score account while the leaderboard account is supplied, so its standings
+/// are final once the mint is frozen. Entries record points as of the wallet's
+/// last update, before any decay since then. A wallet which drops off a full
+/// leaderboard may only reappear once its points change again.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct Leaderboard {
+    /// Account discriminator, always `AccountType::Leaderboard`.
+    pub account_type: AccountType,
+    /// Layout version of the account data.
+    pub version: u8,
+    /// The scoring mint this leaderboard ranks wallets of.
+    pub mint: Pubkey,
+    /// The mint season this leaderboard ranks wallets of.
+    pub season: u32,
+    /// Is `true` if this structure has been initialized
+    pub is_initialized: bool,
+    /// Maximum number of entries held by the leaderboard.
+    pub capacity: u16,
+    /// Entries sorted by descending points. Wallets with equal points are
+    /// ranked by who reached the score first.
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+impl Leaderboard {
+    /// Current layout version of a leaderboard account.
+    pub const VERSION: u8 = 1;
+
+    /// Maximum capacity of a leaderboard.
+    pub const MAX_CAPACITY: u16 = 100;
+
+    /// Size of the data in a leaderboard account with the given capacity.
+    pub fn size(capacity: u16) -> usize {
+        ACCOUNT_HEADER_SIZE + 32 + 4 + 1 + 2 + 4 + capacity as usize * LeaderboardEntry::SIZE
+    }
+
+    /// Records the wallet's points, moving it to its rank or dropping it from
+    /// the leaderboard if it no longer ranks within the capacity.
+    pub fn update(&mut self, wallet: &Pubkey, points: Points) {
+        self.entries.retain(|entry| entry.wallet != *wallet);
+        let rank = self
+            .entries
+            .iter()
+            .position(|entry| entry.points < points)
+            .unwrap_or(self.entries.len());
+        if rank < self.capacity as usize {
+            self.entries.insert(
+                rank,
+                LeaderboardEntry {
+                    wallet: *wallet,
+                    points,
+                },
+            );
+            self.entries.truncate(self.capacity as usize);
+        }
+    }
+
+    /// Removes the wallet from the leaderboard, if it is ranked.
+    pub fn remove(&mut self, wallet: &Pubkey) {
+        self.entries.retain(|entry| entry.wallet != *wallet);
+    }
+
+    /// Moves the old wallet's standing to the new wallet, if the old wallet
+    /// is ranked.
+    pub fn migrate(&mut self, old_wallet: &Pubkey, new_wallet: &Pubkey) {
+        let old_entry = self
+            .entries
+            .iter()
+            .find(|entry| entry.wallet == *old_wallet);
+        if let Some(points) = old_entry.map(|entry| entry.points) {
+            self.entries.retain(|entry| entry.wallet != *old_wallet);
+            self.update(new_wallet, points);
+        }
+    }
+}
+
+/// A wallet's standing on a leaderboard.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct LeaderboardEntry {
+    /// The ranked wallet.
+    pub wallet: Pubkey,
+    /// The wallet's points as of its last update.
+    pub points: Points,
+}
+
+impl LeaderboardEntry {
+    /// Size of a serialized leaderboard entry.
+    pub const SIZE: usize = 32 + 9;
+}
+
+/// Claim nonce data, recording the last nonce of the signed points messages
+/// claimed for a wallet on a scoring mint, across all seasons. Claims must use
+/// a greater nonce, preventing replays. The account is created by the first
+/// `ClaimSignedPoints` for the wallet, is never closed, and its address is
+/// derived from the mint and wallet addresses, see `get_claim_nonce_address`.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct ClaimNonce {
+    /// Account discriminator, always `AccountType::ClaimNonce`.
+    pub account_type: AccountType,
+    /// Layout version of the account data.
+    pub version: u8,
+    /// The scoring mint the signed points messages were claimed on.
+    pub mint: Pubkey,
+    /// The wallet the signed points messages were claimed for.
+    pub wallet: Pubkey,
+    /// Is `true` if this structure has been initialized
+    pub is_initialized: bool,
+    /// Nonce of the last signed points message claimed.
+    pub last_nonce: u64,
+}
+
+impl ClaimNonce {
+    /// Current layout version of a claim nonce account.
+    pub const VERSION: u8 = 1;
+
+    /// Size of the data in a claim nonce account.
+    pub const SIZE: usize = ACCOUNT_HEADER_SIZE + 32 + 32 + 1 + 8;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: UnixTimestamp = SECONDS_PER_DAY as UnixTimestamp;
+
+    fn test_mint(decay_policy: DecayPolicy) -> Mint {
+        Mint {
+            account_type: AccountType::Mint,
+            version: Mint::VERSION,
+            score_authority: Some(Pubkey::new_unique()),
+            pending_score_authority: None,
+            freeze_authority: None,
+            state: MintState::Initialized,
+            underflow_policy: UnderflowPolicy::Reject,
+            allow_self_close: false,
+            start_timestamp: None,
+            end_timestamp: None,
+            decay_policy: Some(decay_policy),
+            season: 0,
+            max_points_per_account: None,
+            max_total_supply: None,
+            total_issued: 0,
+            point_representation: PointRepresentation::Unsigned,
+            stat_names: Vec::new(),
+            metadata_uri: String::new(),
+        }
+    }
+
+    fn test_score_account(points: u64, last_updated: UnixTimestamp) -> ScoreAccount {
+        ScoreAccount {
+            account_type: AccountType::ScoreAccount,
+            version: ScoreAccount::VERSION,
+            mint: Pubkey::new_unique(),
+            wallet: Pubkey::new_unique(),
+            season: 0,
+            state: ScoreAccountState::Initialized,
+            points: Points::Unsigned(points),
+            last_updated,
+            decay_remainder: 0,
+            badges: 0,
+            stats: [0; Mint::MAX_STATS],
+        }
+    }
+
+    #[test]
+    fn test_issued_points_are_not_decayed() {
+        let mint = test_mint(DecayPolicy::HalfLife {
+            half_life_seconds: 30 * SECONDS_PER_DAY,
+        });
+        let mut scoredata = test_score_account(104, 0);
+
+        scoredata.settle_decay(&mint, 30 * DAY);
+        assert_eq!(scoredata.points, Points::Unsigned(52));
+        assert_eq!(scoredata.last_updated, 30 * DAY);
+        scoredata.points = scoredata.points.checked_add(1_000).unwrap();
+        assert_eq!(
+            scoredata.effective_points(&mint, None, 30 * DAY),
+            Points::Unsigned(1_052)
+        );
+        assert_eq!(
+            scoredata.effective_points(&mint, None, 60 * DAY),
+            Points::Unsigned(526)
+        );
+
+        let mint = test_mint(DecayPolicy::Linear { points_per_day: 10 });
+        let mut scoredata = test_score_account(100, 0);
+        scoredata.settle_decay(&mint, DAY / 2);
+        scoredata.points = scoredata.points.checked_add(1_000).unwrap();
+        assert_eq!(
+            scoredata.effective_points(&mint, None, DAY / 2),
+            Points::Unsigned(1_095)
+        );
+        assert_eq!(
+            scoredata.effective_points(&mint, None, 3 * DAY),
+            Points::Unsigned(1_070)
+        );
+    }
+
+    #[test]
+    fn test_linear_decay_independent_of_changes() {
+        let mint = test_mint(DecayPolicy::Linear { points_per_day: 7 });
+        let untouched = test_score_account(50, 0);
+        let mut touched = test_score_account(50, 0);
+        let touches = [1, 17, 12_345, 12_346, 86_399, 86_400, 100_000, 250_001];
+        for &touch in &touches {
+            touched.settle_decay(&mint, touch);
+            assert_eq!(
+                touched.points,
+                untouched.effective_points(&mint, None, touch)
+            );
+        }
+        for end in (0..20).map(|i| 250_001 + i * 9_999) {
+            assert_eq!(
+                touched.effective_points(&mint, None, end),
+                untouched.effective_points(&mint, None, end)
+            );
+        }
+
+        // Points issued along the way decay at the same rate.
+        let mut issued = test_score_account(50, 0);
+        for &touch in &touches {
+            issued.settle_decay(&mint, touch);
+            issued.points = issued.points.checked_add(10).unwrap();
+        }
+        assert_eq!(
+            issued.effective_points(&mint, None, 500_000),
+            untouched
+                .effective_points(&mint, None, 500_000)
+                .checked_add(80)
+                .unwrap()
+        );
+
+        // Fully decayed points stay at zero, and later points decay afresh.
+        touched.settle_decay(&mint, 100 * DAY);
+        assert_eq!(
+            (touched.points, touched.decay_remainder),
+            (Points::Unsigned(0), 0)
+        );
+        touched.points = Points::Unsigned(7);
+        assert_eq!(
+            touched.effective_points(&mint, None, 101 * DAY - 1),
+            Points::Unsigned(1)
+        );
+    }
+
+    #[test]
+    fn test_half_life_decay() {
+        let policy = DecayPolicy::HalfLife {
+            half_life_seconds: 10,
+        };
+        assert_eq!(policy.decay(100, 0, 5), (75, 0));
+        assert_eq!(policy.decay(100, 0, 10), (50, 0));
+        assert_eq!(policy.decay(100, 0, 35), (9, 0));
+        assert_eq!(policy.decay(u64::MAX, 0, 640), (0, 0));
+    }
+
+    #[test]
+    fn test_signed_points_decay_magnitude() {
+        let policy = DecayPolicy::Linear { points_per_day: 24 };
+        assert_eq!(
+            Points::Signed(-100).decay(&policy, 0, 5_000),
+            (Points::Signed(-99), 33_600)
+        );
+        assert_eq!(
+            Points::Signed(100).decay(&policy, 0, 5_000),
+            (Points::Signed(99), 33_600)
+        );
+        assert_eq!(
+            Points::Signed(-100).decay(&policy, 0, 200 * 3_600),
+            (Points::Signed(0), 0)
+        );
+    }
+
+    #[test]
+    fn test_closed_season_decays_until_it_ended() {
+        let mut mint = test_mint(DecayPolicy::Linear { points_per_day: 10 });
+        mint.season = 1;
+        let scoredata = test_score_account(100, 1_000);
+        let ended_at = 1_000 + 3 * DAY;
+        for &unix_timestamp in &[ended_at, ended_at + 1, ended_at + 30 * DAY] {
+            assert_eq!(
+                scoredata.effective_points(&mint, Some(ended_at), unix_timestamp),
+                Points::Unsigned(70)
+            );
+        }
+        assert_eq!(
+            scoredata.effective_points(&mint, Some(ended_at), 1_000 + DAY),
+            Points::Unsigned(90)
+        );
+    }
+
+    fn test_badge_descriptors(indices: &[u8]) -> Vec<BadgeDescriptor> {
+        indices
+            .iter()
+            .map(|&index| BadgeDescriptor {
+                index,
+                name: format!("badge {}", index),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_badges_at_bitset_edges() {
+        let mut scoredata = test_score_account(0, 0);
+        scoredata.toggle_badge(0);
+        scoredata.toggle_badge(63);
+        assert_eq!(scoredata.badges, 1 | 1 << 63);
+        assert!(scoredata.has_badge(0));
+        assert!(scoredata.has_badge(63));
+        assert!(!scoredata.has_badge(1));
+        assert!(!scoredata.has_badge(62));
+        assert!(!scoredata.has_badge(ScoreAccount::MAX_BADGES));
+        assert!(!scoredata.has_badge(u8::MAX));
+        assert_eq!(
+            scoredata.badge_names(&test_badge_descriptors(&[63, 1, 0])),
+            vec!["badge 0", "badge 63"]
+        );
+    }
+
+    #[test]
+    fn test_badge_names_skip_missing_descriptors() {
+        let mut scoredata = test_score_account(0, 0);
+        for &index in &[2, 5, 9] {
+            scoredata.toggle_badge(index);
+        }
+        // Badge 5 has no descriptor, and badge 7 is described but not granted.
+        assert_eq!(
+            scoredata.badge_names(&test_badge_descriptors(&[9, 7, 2])),
+            vec!["badge 2", "badge 9"]
+        );
+        assert!(scoredata.badge_names(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_badge_toggled_twice_is_revoked() {
+        let mut scoredata = test_score_account(0, 0);
+        scoredata.toggle_badge(7);
+        scoredata.toggle_badge(3);
+        scoredata.toggle_badge(7);
+        assert!(!scoredata.has_badge(7));
+        assert!(scoredata.has_badge(3));
+        assert_eq!(scoredata.badges, 1 << 3);
+        assert_eq!(
+            scoredata.badge_names(&test_badge_descriptors(&[3, 7])),
+            vec!["badge 3"]
+        );
+    }
+
+    fn test_leaderboard(capacity: u16) -> Leaderboard {
+        Leaderboard {
+            account_type: AccountType::Leaderboard,
+            version: Leaderboard::VERSION,
+            mint: Pubkey::new_unique(),
+            season: 0,
+            is_initialized: true,
+            capacity,
+            entries: Vec::new(),
+        }
+    }
+
+    fn ranking(leaderboard: &Leaderboard) -> Vec<(Pubkey, Points)> {
+        leaderboard
+            .entries
+            .iter()
+            .map(|entry| (entry.wallet, entry.points))
+            .collect()
+    }
+
+    #[test]
+    fn test_leaderboard_ties_rank_first_to_reach_score() {
+        let mut leaderboard = test_leaderboard(5);
+        let wallets: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        leaderboard.update(&wallets[0], Points::Unsigned(10));
+        leaderboard.update(&wallets[1], Points::Unsigned(20));
+        leaderboard.update(&wallets[2], Points::Unsigned(10));
+        leaderboard.update(&wallets[3], Points::Unsigned(5));
+        // Reaching an existing score ranks after the wallets already there.
+        leaderboard.update(&wallets[3], Points::Unsigned(10));
+        assert_eq!(
+            ranking(&leaderboard),
+            vec![
+                (wallets[1], Points::Unsigned(20)),
+                (wallets[0], Points::Unsigned(10)),
+                (wallets[2], Points::Unsigned(10)),
+                (wallets[3], Points::Unsigned(10)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_leaderboard_truncates_to_capacity() {
+        let mut leaderboard = test_leaderboard(3);
+        let wallets: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        for (i, wallet) in wallets.iter().enumerate().take(4) {
+            leaderboard.update(wallet, Points::Signed(i as i64 * 10 - 15));
+        }
+        assert_eq!(
+            ranking(&leaderboard),
+            vec![
+                (wallets[3], Points::Signed(15)),
+                (wallets[2], Points::Signed(5)),
+                (wallets[1], Points::Signed(-5))
+            ]
+        );
+
+        // A wallet tying the lowest entry of a full leaderboard is not ranked.
+        leaderboard.update(&wallets[4], Points::Signed(-5));
+        assert_eq!(leaderboard.entries.len(), 3);
+        assert!(leaderboard
+            .entries
+            .iter()
+            .all(|entry| entry.wallet != wallets[4]));
+
+        leaderboard.update(&wallets[4], Points::Signed(10));
+        assert_eq!(
+            ranking(&leaderboard),
+            vec![
+                (wallets[3], Points::Signed(15)),
+                (wallets[4], Points::Signed(10)),
+                (wallets[2], Points::Signed(5))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_leaderboard_wallet_drops_off() {
+        let mut leaderboard = test_leaderboard(2);
+        let wallets: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        leaderboard.update(&wallets[0], Points::Unsigned(30));
+        leaderboard.update(&wallets[1], Points::Unsigned(20));
+        leaderboard.update(&wallets[2], Points::Unsigned(25));
+        assert_eq!(
+            ranking(&leaderboard),
+            vec![
+                (wallets[0], Points::Unsigned(30)),
+                (wallets[2], Points::Unsigned(25))
+            ]
+        );
+
+        // A slashed wallet keeps its rank while the dropped wallet is not
+        // ranked again until its own points change.
+        leaderboard.update(&wallets[0], Points::Unsigned(5));
+        assert_eq!(
+            ranking(&leaderboard),
+            vec![
+                (wallets[2], Points::Unsigned(25)),
+                (wallets[0], Points::Unsigned(5))
+            ]
+        );
+        leaderboard.update(&wallets[1], Points::Unsigned(20));
+        assert_eq!(
+            ranking(&leaderboard),
+            vec![
+                (wallets[2], Points::Unsigned(25)),
+                (wallets[1], Points::Unsigned(20))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_leaderboard_remove() {
+        let mut leaderboard = test_leaderboard(2);
+        let wallets: Vec<Pubkey> = (0..2).map(|_| Pubkey::new_unique()).collect();
+        leaderboard.update(&wallets[0], Points::Unsigned(30));
+        leaderboard.update(&wallets[1], Points::Unsigned(20));
+        leaderboard.remove(&wallets[0]);
+        leaderboard.remove(&Pubkey::new_unique());
+        assert_eq!(
+            ranking(&leaderboard),
+            vec![(wallets[1], Points::Unsigned(20))]
+        );
+    }
+
+    #[test]
+    fn test_mint_v0_maps_onto_current_layout() {
+        let score_authority = Pubkey::new_unique();
+        let freeze_authority = Some(Pubkey::new_unique());
+        let mut data = MintV0 {
+            score_authority,
+            freeze_authority,
+            state: MintState::Frozen,
+            metadata_uri: "https://example.com/points.json".to_string(),
+        }
+        .try_to_vec()
+        .unwrap();
+        data.resize(MintV0::SIZE, 0);
+
+        let mintdata =
+            Mint::from(solana_program::borsh::try_from_slice_unchecked::<MintV0>(&data).unwrap());
+        let mut expected = test_mint(DecayPolicy::Linear { points_per_day: 1 });
+        expected.decay_policy = None;
+        expected.score_authority = Some(score_authority);
+        expected.freeze_authority = freeze_authority;
+        expected.state = MintState::Frozen;
+        expected.metadata_uri = "https://example.com/points.json".to_string();
+        assert_eq!(mintdata, expected);
+        assert!(mintdata.try_to_vec().unwrap().len() <= Mint::SIZE);
+    }
+}
@@ -1,19 +1,146 @@
 //! Utilities for scoring program
 
-use crate::error::ScoreError;
+use crate::{error::ScoreError, state::AccountType};
 use borsh::BorshDeserialize;
-use solana_program::{borsh::try_from_slice_unchecked, program_error::ProgramError};
+use solana_program::{
+    account_info::AccountInfo,
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction,
+};
 
-/// Deserialize and ignore if the type doesn't read all the bytes in the data
+/// Deserialize and ignore if the type doesn't read all the bytes in the data.
+/// Fails unless the data has the expected size and is either uninitialized or
+/// of the expected account type, with the current layout version.
 pub fn try_from_slice_checked<T: BorshDeserialize>(
     data: &[u8],
+    data_type: AccountType,
     data_size: usize,
 ) -> Result<T, ProgramError> {
-    if data.len() != data_size {
+    if data.len() != data_size
+        || (data[0] != data_type as u8 && data[0] != AccountType::Uninitialized as u8)
+    {
         return Err(ScoreError::DataTypeMismatch.into());
     }
+    assert_account_version(data)?;
 
     let result: T = try_from_slice_unchecked(data)?;
 
     Ok(result)
 }
+
+/// Fails unless the account data, led by its account header, has the current
+/// layout version of its account type.
+pub fn assert_account_version(data: &[u8]) -> ProgramResult {
+    let account_type = data
+        .first()
+        .and_then(|account_type| AccountType::try_from_slice(&[*account_type]).ok())
+        .ok_or(ScoreError::DataTypeMismatch)?;
+    if data.get(1) != Some(&account_type.current_version()) {
+        return Err(ScoreError::InvalidAccountVersion.into());
+    }
+    Ok(())
+}
+
+/// Fails unless the account is owned by the given program
+pub fn assert_owned_by(account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
+    if account.owner != owner {
+        return Err(ScoreError::IncorrectOwner.into());
+    }
+    Ok(())
+}
+
+/// Creates a program derived account, funding it to be rent-exempt. Accounts
+/// which were pre-funded by another party are topped up, allocated and
+/// assigned rather than created.
+pub fn create_pda_account<'a>(
+    payer: &AccountInfo<'a>,
+    rent: &Rent,
+    space: usize,
+    owner: &Pubkey,
+    system_program: &AccountInfo<'a>,
+    new_pda_account: &AccountInfo<'a>,
+    new_pda_signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    if new_pda_account.lamports() > 0 {
+        let required_lamports = rent
+            .minimum_balance(space)
+            .max(1)
+            .saturating_sub(new_pda_account.lamports());
+
+        if required_lamports > 0 {
+            invoke(
+                &system_instruction::transfer(payer.key, new_pda_account.key, required_lamports),
+                &[
+                    payer.clone(),
+                    new_pda_account.clone(),
+                    system_program.clone(),
+                ],
+            )?;
+        }
+
+        invoke_signed(
+            &system_instruction::allocate(new_pda_account.key, space as u64),
+            &[new_pda_account.clone(), system_program.clone()],
+            &[new_pda_signer_seeds],
+        )?;
+
+        invoke_signed(
+            &system_instruction::assign(new_pda_account.key, owner),
+            &[new_pda_account.clone(), system_program.clone()],
+            &[new_pda_signer_seeds],
+        )
+    } else {
+        invoke_signed(
+            &system_instruction::create_account(
+                payer.key,
+                new_pda_account.key,
+                rent.minimum_balance(space).max(1),
+                space as u64,
+                owner,
+            ),
+            &[
+                payer.clone(),
+                new_pda_account.clone(),
+                system_program.clone(),
+            ],
+            &[new_pda_signer_seeds],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_slice_checked_version() {
+        let mut data = vec![0; 4];
+        assert_eq!(
+            try_from_slice_checked::<[u8; 4]>(&data, AccountType::Leaderboard, 4),
+            Ok([0; 4])
+        );
+
+        data[0] = AccountType::Leaderboard as u8;
+        data[1] = crate::state::Leaderboard::VERSION;
+        assert!(try_from_slice_checked::<[u8; 4]>(&data, AccountType::Leaderboard, 4).is_ok());
+
+        for &version in &[0, crate::state::Leaderboard::VERSION + 1] {
+            data[1] = version;
+            assert_eq!(
+                try_from_slice_checked::<[u8; 4]>(&data, AccountType::Leaderboard, 4),
+                Err(ScoreError::InvalidAccountVersion.into())
+            );
+        }
+
+        data[0] = AccountType::Uninitialized as u8;
+        assert_eq!(
+            try_from_slice_checked::<[u8; 4]>(&data, AccountType::Leaderboard, 4),
+            Err(ScoreError::InvalidAccountVersion.into())
+        );
+    }
+}